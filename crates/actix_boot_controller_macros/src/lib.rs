@@ -0,0 +1,209 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Attribute, FnArg, GenericArgument, ImplItem, ImplItemFn, ItemImpl, LitStr, PathArguments, Type};
+
+const ROUTE_METHODS: [&str; 6] = ["get", "post", "put", "patch", "delete", "head"];
+
+struct Route {
+  method: syn::Ident,
+  path: LitStr,
+}
+
+struct Endpoint {
+  routes: Vec<Route>,
+  function: ImplItemFn,
+}
+
+#[proc_macro_attribute]
+pub fn controller(attr: TokenStream, item: TokenStream) -> TokenStream {
+  impl_controller(
+    parse_macro_input!(attr as LitStr),
+    parse_macro_input!(item as ItemImpl),
+  )
+  .unwrap_or_else(|err| err.to_compile_error())
+  .into()
+}
+
+fn impl_controller(prefix: LitStr, mut item: ItemImpl) -> syn::Result<proc_macro2::TokenStream> {
+  if let Some((_, path, _)) = &item.trait_ {
+    return Err(syn::Error::new_spanned(path, "Use controller only on inherent impl blocks"));
+  }
+
+  let self_ty = item.self_ty.clone();
+  let mut endpoints = Vec::new();
+
+  for impl_item in item.items.iter_mut() {
+    let ImplItem::Fn(function) = impl_item else {
+      continue;
+    };
+
+    let routes = take_routes(&mut function.attrs)?;
+
+    if !routes.is_empty() {
+      endpoints.push(Endpoint {
+        routes,
+        function: function.clone(),
+      });
+    }
+  }
+
+  let mut resources: Vec<(String, Vec<proc_macro2::TokenStream>)> = Vec::new();
+
+  for endpoint in &endpoints {
+    let handler = generate_handler(&endpoint.function)?;
+
+    for route in &endpoint.routes {
+      let method = &route.method;
+      let path = route.path.value();
+      let route = quote! {
+        .route(actix_web::web::#method().to(#handler))
+      };
+
+      match resources.iter_mut().find(|(resource_path, _)| *resource_path == path) {
+        Some((_, routes)) => routes.push(route),
+        None => resources.push((path, vec![route])),
+      }
+    }
+  }
+
+  let dependencies = endpoints.iter().flat_map(|endpoint| {
+    let handler = endpoint.function.sig.ident.to_string();
+
+    endpoint.function.sig.inputs.iter().filter_map(move |input| {
+      let FnArg::Typed(pat_type) = input else {
+        return None;
+      };
+
+      let inner = injected_type(&pat_type.ty)?;
+
+      Some(quote! {
+        actix_boot::di::Dependency {
+          field: #handler,
+          target: actix_boot::di::DependencyTarget::Injected {
+            type_id: std::any::TypeId::of::<#inner>,
+            name: std::any::type_name::<#inner>,
+          },
+          optional: false,
+          deferred: true,
+        }
+      })
+    })
+  });
+
+  let resources = resources.iter().map(|(path, routes)| {
+    quote! {
+      .service(actix_web::web::resource(#path) #(#routes)*)
+    }
+  });
+
+  Ok(quote! {
+    #item
+
+    impl actix_boot::controller::Controller for #self_ty {
+      fn configure(service_config: &mut actix_web::web::ServiceConfig, context: &actix_boot::di::DIContext) {
+        service_config.service(
+          actix_web::web::scope(#prefix)
+            .app_data(actix_web::web::Data::from(context.get_service::<Self>()))
+            #(#resources)*
+        );
+      }
+
+      fn handler_dependencies() -> Vec<actix_boot::di::Dependency> {
+        vec![#(#dependencies),*]
+      }
+    }
+
+    actix_boot::discovery::inventory::submit! {
+      actix_boot::discovery::Component {
+        name: concat!(module_path!(), "::", stringify!(#self_ty)),
+        module_path: module_path!(),
+        kind: actix_boot::discovery::ComponentKind::Controller,
+        profiles: &[],
+        conditions: &[],
+        register: actix_boot::discovery::register_controller::<#self_ty>,
+        info: actix_boot::di::ComponentInfo::controller::<#self_ty>,
+      }
+    }
+  })
+}
+
+fn take_routes(attrs: &mut Vec<Attribute>) -> syn::Result<Vec<Route>> {
+  let mut routes = Vec::new();
+  let mut kept = Vec::new();
+
+  for attr in attrs.drain(..) {
+    let method = attr.path().segments.last()
+      .map(|segment| segment.ident.clone())
+      .filter(|ident| ROUTE_METHODS.contains(&ident.to_string().as_str()));
+
+    match method {
+      Some(method) => routes.push(Route {
+        method,
+        path: attr.parse_args()?,
+      }),
+      None => kept.push(attr),
+    }
+  }
+
+  *attrs = kept;
+  Ok(routes)
+}
+
+fn injected_type(ty: &Type) -> Option<&Type> {
+  let Type::Path(type_path) = ty else {
+    return None;
+  };
+
+  let segment = type_path.path.segments.last().filter(|segment| segment.ident == "Inject")?;
+
+  let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+    return None;
+  };
+
+  match arguments.args.first() {
+    Some(GenericArgument::Type(inner)) => Some(inner),
+    _ => None,
+  }
+}
+
+fn generate_handler(function: &ImplItemFn) -> syn::Result<proc_macro2::TokenStream> {
+  let ident = &function.sig.ident;
+
+  if function.sig.asyncness.is_none() {
+    return Err(syn::Error::new_spanned(&function.sig, "Controller routes must be async"));
+  }
+
+  let mut receiver = None;
+  let mut args = Vec::new();
+  let mut arg_idents = Vec::new();
+
+  for (index, input) in function.sig.inputs.iter().enumerate() {
+    match input {
+      FnArg::Receiver(self_arg) => {
+        if self_arg.reference.is_none() || self_arg.mutability.is_some() {
+          return Err(syn::Error::new_spanned(self_arg, "Controller routes must take &self"));
+        }
+
+        receiver = Some(quote! { __controller: actix_web::web::Data<Self>, });
+      }
+      FnArg::Typed(pat_type) => {
+        let arg_ident = format_ident!("__arg{}", index);
+        let arg_ty = &pat_type.ty;
+
+        args.push(quote! { #arg_ident: #arg_ty });
+        arg_idents.push(arg_ident);
+      }
+    }
+  }
+
+  let (receiver, call) = match receiver {
+    Some(receiver) => (receiver, quote! { __controller.#ident(#(#arg_idents),*).await }),
+    None => (quote! {}, quote! { Self::#ident(#(#arg_idents),*).await }),
+  };
+
+  Ok(quote! {
+    |__request: actix_web::HttpRequest, #receiver #(#args),*| async move {
+      actix_web::Responder::respond_to(#call, &__request).map_into_boxed_body()
+    }
+  })
+}
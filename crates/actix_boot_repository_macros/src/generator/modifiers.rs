@@ -1,4 +1,3 @@
-use quote::quote;
 use syn::Path;
 use crate::parse::{QueryModifier, OrderDirection};
 use super::filter::to_pascal_case;
@@ -46,6 +45,8 @@ pub fn generate_modifiers(
           .limit(per_page)
         });
       },
+      QueryModifier::Pageable => {},
+      QueryModifier::Cursor => {},
     }
   }
 
@@ -54,11 +55,38 @@ pub fn generate_modifiers(
   })
 }
 
+pub fn generate_keyset_orders(
+  modifiers: &[QueryModifier],
+  module: &Path,
+) -> proc_macro2::TokenStream {
+  use quote::quote;
+
+  let orders = modifiers.iter().filter_map(|modifier| match modifier {
+    QueryModifier::OrderBy(col_name, direction) => {
+      let col_ident = syn::Ident::new(&to_pascal_case(col_name), proc_macro2::Span::call_site());
+
+      let order_type = match direction {
+        OrderDirection::Asc => quote! { sea_orm::Order::Asc },
+        OrderDirection::Desc => quote! { sea_orm::Order::Desc },
+      };
+
+      Some(quote! { (#module::Column::#col_ident, #order_type) })
+    },
+    _ => None,
+  });
+
+  quote! {
+    vec![#(#orders),*]
+  }
+}
+
 pub fn count_modifier_params(modifiers: &[QueryModifier]) -> usize {
   modifiers.iter().map(|m| match m {
     QueryModifier::Limit => 1,
     QueryModifier::Offset => 1,
     QueryModifier::Paginate => 2,
+    QueryModifier::Pageable => 1,
+    QueryModifier::Cursor => 2,
     QueryModifier::OrderBy(_, _) => 0,
     QueryModifier::Distinct => 0,
   }).sum()
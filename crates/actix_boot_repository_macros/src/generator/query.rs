@@ -2,7 +2,7 @@ use quote::quote;
 use syn::Path;
 use crate::parse::{QuerySpec, UpdateSpec, AggregateSpec, QueryModifier};
 use super::filter::{FilterParam, generate_all_filters};
-use super::modifiers::generate_modifiers;
+use super::modifiers::{generate_keyset_orders, generate_modifiers};
 
 pub fn build_find_by_query(
   spec: &QuerySpec,
@@ -30,8 +30,46 @@ pub fn build_find_all_by_query(
   module: &Path,
 ) -> syn::Result<proc_macro2::TokenStream> {
   let has_paginate = spec.modifiers.iter().any(|m| matches!(m, QueryModifier::Paginate));
+  let has_pageable = spec.modifiers.iter().any(|m| matches!(m, QueryModifier::Pageable));
+  let has_cursor = spec.modifiers.iter().any(|m| matches!(m, QueryModifier::Cursor));
 
-  if has_paginate {
+  if has_cursor {
+    let filters = generate_all_filters(&spec.filters, params, module)?;
+    let orders = generate_keyset_orders(&spec.modifiers, module);
+
+    let unordered = spec.modifiers.iter()
+      .filter(|m| !matches!(m, QueryModifier::OrderBy(_, _)))
+      .cloned()
+      .collect::<Vec<_>>();
+
+    let modifiers = generate_modifiers(&unordered, module)?;
+
+    Ok(quote! {
+      {
+        use sea_orm::QuerySelect;
+
+        let select = #module::Entity::find()
+          #filters
+          #modifiers;
+
+        actix_boot::repository::operations::find_cursor(&self.db, select, #orders, cursor.as_deref(), size).await
+      }
+    })
+  } else if has_pageable {
+    let filters = generate_all_filters(&spec.filters, params, module)?;
+    let modifiers = generate_modifiers(&spec.modifiers, module)?;
+
+    Ok(quote! {
+      {
+        use sea_orm::{QueryOrder, QuerySelect};
+
+        let select = actix_boot::repository::operations::apply_sort(#module::Entity::find() #filters, &pageable.sort)?
+          #modifiers;
+
+        Ok(actix_boot::repository::operations::find_page(&self.db, select, pageable.page, pageable.size).await?)
+      }
+    })
+  } else if has_paginate {
     let filters = generate_all_filters(&spec.filters, params, module)?;
 
     Ok(quote! {
@@ -45,7 +83,7 @@ pub fn build_find_all_by_query(
           .await?;
 
         let total_pages = if per_page > 0 {
-          (total_items + per_page - 1) / per_page
+          total_items.div_ceil(per_page)
         } else {
           0
         };
@@ -2,7 +2,8 @@ use proc_macro::TokenStream;
 use proc_macro2::Span;
 use quote::quote;
 use syn::parse::{Parse, ParseStream};
-use syn::{TraitItem, parse_macro_input, ItemTrait, Path};
+use syn::punctuated::Punctuated;
+use syn::{Ident, LitStr, Token, TraitItem, TraitItemFn, Type, parse_macro_input, ItemTrait, Path, parenthesized};
 use crate::generator::generate_query;
 
 mod generator;
@@ -14,18 +15,42 @@ pub fn repository(attr: TokenStream, item: TokenStream) -> TokenStream {
     parse_macro_input!(attr as RepositoryAttr),
     parse_macro_input!(item as ItemTrait),
   )
-  .unwrap_or_else(|err| err.to_compile_error().into())
+  .unwrap_or_else(|err| err.to_compile_error())
   .into()
 }
 
 struct RepositoryAttr {
   module: Path,
+  datasource: Option<LitStr>,
+  read_only: bool,
+  binds: Vec<Type>,
 }
 
 impl Parse for RepositoryAttr {
   fn parse(input: ParseStream) -> syn::Result<Self> {
     let module: Path = input.parse()?;
-    Ok(RepositoryAttr { module })
+    let mut datasource = None;
+    let mut read_only = false;
+    let mut binds = Vec::new();
+
+    while input.parse::<Option<Token![,]>>()?.is_some() && !input.is_empty() {
+      let key: Ident = input.parse()?;
+
+      if key == "datasource" {
+        input.parse::<Token![=]>()?;
+        datasource = Some(input.parse()?);
+      } else if key == "read_only" {
+        read_only = true;
+      } else if key == "binds" {
+        let content;
+        parenthesized!(content in input);
+        binds.extend(Punctuated::<Type, Token![,]>::parse_terminated(&content)?);
+      } else {
+        return Err(syn::Error::new_spanned(key, "Expected datasource = \"...\", read_only or binds(...)"));
+      }
+    }
+
+    Ok(RepositoryAttr { module, datasource, read_only, binds })
   }
 }
 
@@ -34,15 +59,80 @@ fn impl_repository(attr: RepositoryAttr, item: ItemTrait) -> syn::Result<proc_ma
   let struct_name = ident.to_string().replace("Base", "");
   let struct_ident = syn::Ident::new(&struct_name, Span::call_site());
   let module = attr.module;
+  let datasource = attr.datasource.map(|datasource| quote! {
+    actix_boot::discovery::inventory::submit! {
+      actix_boot::discovery::DataSourceComponent {
+        repository: std::any::TypeId::of::<#struct_ident>,
+        datasource: #datasource,
+      }
+    }
+  });
+
+  let bindings = attr.binds.iter().map(|interface| quote! {
+    impl actix_boot::di::Implements<#interface> for #struct_ident {
+      fn upcast(self: std::sync::Arc<Self>) -> std::sync::Arc<#interface> {
+        self
+      }
+    }
+
+    actix_boot::discovery::inventory::submit! {
+      actix_boot::discovery::BindingComponent {
+        interface: stringify!(#interface),
+        interface_id: std::any::TypeId::of::<#interface>,
+        implementation: concat!(module_path!(), "::", stringify!(#struct_ident)),
+        name: None,
+        primary: false,
+        profiles: &[],
+        conditions: &[],
+        bind: |context| context.bind_repository::<#interface, #struct_ident>(),
+      }
+    }
+  });
 
   let functions = item.items.iter()
     .filter_map(|item| match item {
       TraitItem::Fn(function) => Some(function),
-      _ => return None,
+      _ => None,
+    })
+    .map(|function| {
+      if attr.read_only {
+        check_read_only(function)?;
+      }
+
+      generate_query(function, &module)
     })
-    .map(|function| generate_query(function, &module))
     .collect::<syn::Result<Vec<_>>>()?;
 
+  let crud = (!attr.read_only).then(|| quote! {
+    impl actix_boot::repository::CrudRepository for #struct_ident {
+      type ActiveModel = #module::ActiveModel;
+
+      fn save(&self, model: Self::ActiveModel) -> impl std::future::Future<Output = std::result::Result<Self::Model, sea_orm::DbErr>> + Send {
+        actix_boot::repository::operations::save(&self.db, model)
+      }
+
+      fn insert(&self, model: Self::ActiveModel) -> impl std::future::Future<Output = std::result::Result<Self::Model, sea_orm::DbErr>> + Send {
+        sea_orm::ActiveModelTrait::insert(model, &self.db)
+      }
+
+      fn save_all(&self, models: Vec<Self::ActiveModel>) -> impl std::future::Future<Output = std::result::Result<Vec<Self::Model>, sea_orm::DbErr>> + Send {
+        actix_boot::repository::operations::save_all(&self.db, models)
+      }
+
+      fn delete_by_id(&self, id: Self::Id) -> impl std::future::Future<Output = std::result::Result<sea_orm::DeleteResult, sea_orm::DbErr>> + Send {
+        <#module::Entity as sea_orm::EntityTrait>::delete_by_id(id).exec(&self.db)
+      }
+
+      fn delete_all_by_ids(&self, ids: Vec<Self::Id>) -> impl std::future::Future<Output = std::result::Result<sea_orm::DeleteResult, sea_orm::DbErr>> + Send {
+        actix_boot::repository::operations::delete_all_by_ids::<#module::Entity, _>(&self.db, ids)
+      }
+
+      fn delete_all(&self) -> impl std::future::Future<Output = std::result::Result<sea_orm::DeleteResult, sea_orm::DbErr>> + Send {
+        <#module::Entity as sea_orm::EntityTrait>::delete_many().exec(&self.db)
+      }
+    }
+  });
+
   Ok(quote! {
     struct #struct_ident {
       db: sea_orm::DatabaseConnection,
@@ -54,6 +144,16 @@ fn impl_repository(attr: RepositoryAttr, item: ItemTrait) -> syn::Result<proc_ma
       }
     }
 
+    impl actix_boot::di::Injectable for #struct_ident {
+      fn try_resolve(context: &actix_boot::di::DIContext) -> Result<std::sync::Arc<Self>, actix_boot::di::DIError> {
+        context.try_get_repository::<Self>()
+      }
+
+      fn component_info() -> actix_boot::di::ComponentInfo {
+        actix_boot::di::ComponentInfo::repository::<Self>()
+      }
+    }
+
     impl actix_boot::di::GetOrCreate for #struct_ident {
       fn get_or_create(ctx: &actix_boot::di::DIContext) -> actix_web::web::Data<Self> {
         actix_web::web::Data::from(ctx.get_repository::<#struct_ident>())
@@ -63,15 +163,19 @@ fn impl_repository(attr: RepositoryAttr, item: ItemTrait) -> syn::Result<proc_ma
     impl actix_boot::repository::Repository for #struct_ident {
       type Model = #module::Model;
 
+      type Id = <<#module::Entity as sea_orm::EntityTrait>::PrimaryKey as sea_orm::PrimaryKeyTrait>::ValueType;
+    }
+
+    impl actix_boot::repository::ReadRepository for #struct_ident {
       fn find_all(&self) -> impl std::future::Future<Output = std::result::Result<Vec<Self::Model>, sea_orm::DbErr>> {
         #module::Entity::find().all(&self.db)
       }
 
-      fn find(&self, id: i32) -> impl std::future::Future<Output = std::result::Result<Option<Self::Model>, sea_orm::DbErr>> {
+      fn find(&self, id: Self::Id) -> impl std::future::Future<Output = std::result::Result<Option<Self::Model>, sea_orm::DbErr>> {
         #module::Entity::find_by_id(id).one(&self.db)
       }
 
-      fn exists(&self, id: i32) -> impl std::future::Future<Output = std::result::Result<bool, sea_orm::DbErr>> + Send {
+      fn exists(&self, id: Self::Id) -> impl std::future::Future<Output = std::result::Result<bool, sea_orm::DbErr>> + Send {
         async move {
           #module::Entity::find_by_id(id)
             .count(&self.db)
@@ -79,10 +183,63 @@ fn impl_repository(attr: RepositoryAttr, item: ItemTrait) -> syn::Result<proc_ma
             .map(|count| count > 0)
         }
       }
+
+      fn count(&self) -> impl std::future::Future<Output = std::result::Result<u64, sea_orm::DbErr>> + Send {
+        sea_orm::PaginatorTrait::count(<#module::Entity as sea_orm::EntityTrait>::find(), &self.db)
+      }
     }
 
+    impl actix_boot::repository::PagingRepository for #struct_ident {
+      fn find_page(&self, page: u64, per_page: u64) -> impl std::future::Future<Output = std::result::Result<actix_boot::repository::Paginator<Self::Model>, sea_orm::DbErr>> + Send {
+        actix_boot::repository::operations::find_page(&self.db, <#module::Entity as sea_orm::EntityTrait>::find(), page, per_page)
+      }
+
+      fn find_all_sorted(&self, sort: &actix_boot::repository::Sort) -> impl std::future::Future<Output = std::result::Result<Vec<Self::Model>, actix_boot::repository::QueryError>> + Send {
+        async move {
+          Ok(actix_boot::repository::operations::apply_sort(<#module::Entity as sea_orm::EntityTrait>::find(), sort)?
+            .all(&self.db)
+            .await?)
+        }
+      }
+
+      fn find_all_pageable(&self, pageable: &actix_boot::repository::Pageable) -> impl std::future::Future<Output = std::result::Result<actix_boot::repository::Paginator<Self::Model>, actix_boot::repository::QueryError>> + Send {
+        actix_boot::repository::operations::find_pageable(&self.db, <#module::Entity as sea_orm::EntityTrait>::find(), pageable)
+      }
+    }
+
+    #crud
+
     impl #struct_ident {
       #(#functions)*
     }
+
+    actix_boot::discovery::inventory::submit! {
+      actix_boot::discovery::Component {
+        name: concat!(module_path!(), "::", stringify!(#struct_ident)),
+        module_path: module_path!(),
+        kind: actix_boot::discovery::ComponentKind::Repository,
+        profiles: &[],
+        conditions: &[],
+        register: actix_boot::discovery::register_repository::<#struct_ident>,
+        info: actix_boot::di::ComponentInfo::repository::<#struct_ident>,
+      }
+    }
+
+    #datasource
+
+    #(#bindings)*
   })
 }
+
+fn check_read_only(function: &TraitItemFn) -> syn::Result<()> {
+  let name = function.sig.ident.to_string();
+
+  if name.starts_with("update_") || name.starts_with("delete_") {
+    return Err(syn::Error::new_spanned(
+      &function.sig.ident,
+      format!("Read only repository can not declare write method '{}'", name),
+    ));
+  }
+
+  Ok(())
+}
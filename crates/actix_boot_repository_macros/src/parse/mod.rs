@@ -3,6 +3,7 @@ use std::slice::Iter;
 use quote::quote;
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum ParseToken {
   FindBy(QuerySpec),
   FindAllBy(QuerySpec),
@@ -41,6 +42,8 @@ pub enum QueryModifier {
   Limit,
   Offset,
   Paginate,
+  Pageable,
+  Cursor,
   Distinct,
 }
 
@@ -58,7 +61,7 @@ pub struct ParseTokenCol {
 
 impl ParseTokenCol {
   pub fn filters(&self) -> Vec<ColFilter> {
-    if self.filters.len() == 0 {
+    if self.filters.is_empty() {
       vec![ColFilter::Eq]
     } else {
       self.filters.clone()
@@ -296,7 +299,7 @@ impl ParseToken {
         filters,
       });
 
-      if saw_and && matches!(iter.peek(), None | Some(&&"order") | Some(&&"limit") | Some(&&"offset") | Some(&&"paginate") | Some(&&"distinct")) {
+      if saw_and && matches!(iter.peek(), None | Some(&&"order") | Some(&&"limit") | Some(&&"offset") | Some(&&"paginate") | Some(&&"pageable") | Some(&&"cursor") | Some(&&"keyset") | Some(&&"distinct")) {
         return Err(syn::Error::new(
           proc_macro2::Span::call_site(),
           "Expected column name after 'and'"
@@ -384,6 +387,14 @@ impl ParseToken {
           iter.next();
           modifiers.push(QueryModifier::Paginate);
         },
+        "pageable" => {
+          iter.next();
+          modifiers.push(QueryModifier::Pageable);
+        },
+        "cursor" | "keyset" => {
+          iter.next();
+          modifiers.push(QueryModifier::Cursor);
+        },
         "distinct" => {
           iter.next();
           modifiers.push(QueryModifier::Distinct);
@@ -392,11 +403,21 @@ impl ParseToken {
       }
     }
 
+    let has_cursor = modifiers.iter().any(|m| matches!(m, QueryModifier::Cursor));
+    let has_paging = modifiers.iter().any(|m| matches!(m, QueryModifier::Limit | QueryModifier::Offset | QueryModifier::Paginate | QueryModifier::Pageable));
+
+    if has_cursor && has_paging {
+      return Err(syn::Error::new(
+        proc_macro2::Span::call_site(),
+        "'cursor' and 'keyset' can not be combined with 'limit', 'offset', 'paginate' or 'pageable'"
+      ));
+    }
+
     Ok(modifiers)
   }
 
   fn is_modifier_keyword(word: &str) -> bool {
-    matches!(word, "order" | "limit" | "offset" | "paginate" | "distinct")
+    matches!(word, "order" | "limit" | "offset" | "paginate" | "pageable" | "cursor" | "keyset" | "distinct")
   }
 }
 
@@ -0,0 +1,285 @@
+use quote::quote;
+use syn::{Expr, GenericArgument, LitStr, PathArguments, Type};
+
+const REPOSITORY_SUFFIX: &str = "_repository";
+
+const SERVICE_SUFFIX: &str = "_service";
+
+pub struct Field {
+  pub member: syn::Member,
+  pub ty: Type,
+  pub injection: Injection,
+}
+
+pub enum Injection {
+  Inject { name: Option<LitStr> },
+  Instance,
+  Config(LitStr),
+  Default,
+  Init(Expr),
+  Convention,
+}
+
+impl Injection {
+  pub fn parse(field: &syn::Field) -> syn::Result<Self> {
+    let mut injection = Injection::Convention;
+
+    for attr in &field.attrs {
+      let parsed = if attr.path().is_ident("inject") {
+        let mut name = None;
+        let mut instance = false;
+
+        if !matches!(attr.meta, syn::Meta::Path(_)) {
+          attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("name") {
+              name = Some(meta.value()?.parse()?);
+              Ok(())
+            } else if meta.path.is_ident("instance") {
+              instance = true;
+              Ok(())
+            } else {
+              Err(meta.error("Expected #[inject], #[inject(name = \"...\")] or #[inject(instance)]"))
+            }
+          })?;
+        }
+
+        if instance && name.is_some() {
+          return Err(syn::Error::new_spanned(attr, "Instance injection can not be named"));
+        }
+
+        if instance { Injection::Instance } else { Injection::Inject { name } }
+      } else if attr.path().is_ident("config") {
+        Injection::Config(attr.parse_args()?)
+      } else if attr.path().is_ident("default") {
+        Injection::Default
+      } else if attr.path().is_ident("init") {
+        Injection::Init(attr.parse_args()?)
+      } else {
+        continue;
+      };
+
+      if !matches!(injection, Injection::Convention) {
+        return Err(syn::Error::new_spanned(attr, "Field can only have one injection attribute"));
+      }
+
+      injection = parsed;
+    }
+
+    Ok(injection)
+  }
+}
+
+impl Field {
+  pub fn quote(&self) -> syn::Result<proc_macro2::TokenStream> {
+    let ty = &self.ty;
+    let optional = option_inner(ty);
+
+    match &self.injection {
+      Injection::Inject { name } => Ok(Self::quote_inject(name.as_ref(), optional.unwrap_or(ty), optional.is_some())),
+      Injection::Instance if optional.is_some() => Ok(quote! { context.try_get_instance().ok() }),
+      Injection::Instance => Ok(quote! { context.try_get_instance()? }),
+      Injection::Config(path) => Ok(quote! { context.try_get_config(#path)? }),
+      Injection::Default => Ok(quote! { Default::default() }),
+      Injection::Init(expr) => Ok(quote! { #expr }),
+      Injection::Convention => self.quote_convention(optional),
+    }
+  }
+
+  pub fn dependency(&self) -> Option<proc_macro2::TokenStream> {
+    let field_name = self.name();
+    let optional = option_inner(&self.ty);
+    let ty = optional.unwrap_or(&self.ty);
+    let is_optional = optional.is_some();
+    let mut deferred = false;
+
+    let target = match &self.injection {
+      Injection::Default | Injection::Init(_) => return None,
+      Injection::Config(path) => quote! {
+        actix_boot::di::DependencyTarget::Config { path: #path }
+      },
+      Injection::Instance => {
+        let inner = generic_inner(ty, "Arc")?;
+
+        quote! {
+          actix_boot::di::DependencyTarget::Instance {
+            type_id: std::any::TypeId::of::<#inner>,
+            ty: std::any::type_name::<#inner>,
+          }
+        }
+      }
+      Injection::Inject { name: Some(name) } => {
+        let inner = generic_inner(ty, "Arc")?;
+
+        quote! {
+          actix_boot::di::DependencyTarget::Named {
+            type_id: std::any::TypeId::of::<#inner>,
+            ty: std::any::type_name::<#inner>,
+            name: #name,
+          }
+        }
+      }
+      Injection::Inject { name: None } | Injection::Convention => {
+        if let Some(inner) = generic_inner(ty, "Lazy").or_else(|| generic_inner(ty, "Provider")) {
+          deferred = true;
+
+          quote! {
+            actix_boot::di::DependencyTarget::Component(<#inner as actix_boot::di::Injectable>::component_info)
+          }
+        } else if is_trait_object_vec(ty) {
+          let inner = generic_inner(generic_inner(ty, "Vec")?, "Arc")?;
+
+          quote! {
+            actix_boot::di::DependencyTarget::Collection {
+              type_id: std::any::TypeId::of::<#inner>,
+              name: std::any::type_name::<#inner>,
+            }
+          }
+        } else if is_trait_object_arc(ty) {
+          let inner = generic_inner(ty, "Arc")?;
+
+          quote! {
+            actix_boot::di::DependencyTarget::Binding {
+              type_id: std::any::TypeId::of::<#inner>,
+              name: std::any::type_name::<#inner>,
+            }
+          }
+        } else if is_optional {
+          let inner = generic_inner(ty, "Arc")?;
+
+          quote! {
+            actix_boot::di::DependencyTarget::Injected {
+              type_id: std::any::TypeId::of::<#inner>,
+              name: std::any::type_name::<#inner>,
+            }
+          }
+        } else {
+          let inner = generic_inner(ty, "Arc")?;
+          let by_convention = matches!(self.injection, Injection::Convention);
+
+          let info = if by_convention && field_name.ends_with(REPOSITORY_SUFFIX) {
+            quote! { actix_boot::di::ComponentInfo::repository::<#inner> }
+          } else if by_convention && field_name.ends_with(SERVICE_SUFFIX) {
+            quote! { actix_boot::di::ComponentInfo::service::<#inner> }
+          } else {
+            quote! { <#inner as actix_boot::di::Injectable>::component_info }
+          };
+
+          quote! {
+            actix_boot::di::DependencyTarget::Component(#info)
+          }
+        }
+      }
+    };
+
+    Some(quote! {
+      actix_boot::di::Dependency {
+        field: #field_name,
+        target: #target,
+        optional: #is_optional,
+        deferred: #deferred,
+      }
+    })
+  }
+
+  fn name(&self) -> String {
+    match &self.member {
+      syn::Member::Named(ident) => ident.to_string(),
+      syn::Member::Unnamed(index) => index.index.to_string(),
+    }
+  }
+
+  fn quote_inject(name: Option<&LitStr>, ty: &Type, optional: bool) -> proc_macro2::TokenStream {
+    let (resolve, try_resolve) = match name {
+      Some(name) => (
+        quote! { context.try_get_named(#name)? },
+        quote! { context.try_get_named(#name).ok() },
+      ),
+      None if is_wrapper(ty, "Lazy") => (
+        quote! { context.try_lazy()? },
+        quote! { Some(context.try_lazy()?) },
+      ),
+      None if is_wrapper(ty, "Provider") => (
+        quote! { context.try_provider()? },
+        quote! { Some(context.try_provider()?) },
+      ),
+      None if is_trait_object_vec(ty) => (
+        quote! { context.try_get_all()? },
+        quote! { Some(context.try_get_all()?) },
+      ),
+      None if is_trait_object_arc(ty) => (
+        quote! { context.try_get_binding()? },
+        quote! { context.try_get_binding().ok() },
+      ),
+      None => (
+        quote! { context.try_inject()? },
+        quote! { context.try_resolve().ok() },
+      ),
+    };
+
+    if optional { try_resolve } else { resolve }
+  }
+
+  fn quote_convention(&self, optional: Option<&Type>) -> syn::Result<proc_macro2::TokenStream> {
+    let ty = optional.unwrap_or(&self.ty);
+
+    if optional.is_some() || is_trait_object_arc(ty) || is_trait_object_vec(ty) || is_wrapper(ty, "Lazy") || is_wrapper(ty, "Provider") {
+      return Ok(Self::quote_inject(None, ty, optional.is_some()));
+    }
+
+    let syn::Member::Named(ident) = &self.member else {
+      return Ok(Self::quote_inject(None, ty, false));
+    };
+
+    let field_name = ident.to_string();
+
+    if field_name.ends_with(REPOSITORY_SUFFIX) {
+      return Ok(quote! { context.get_repository() });
+    }
+
+    if field_name.ends_with(SERVICE_SUFFIX) {
+      return Ok(quote! { context.try_get_service()? });
+    }
+
+    Err(syn::Error::new_spanned(
+      &self.ty,
+      "Field must be annotated with #[inject], #[config], #[default] or #[init], or have _repository or _service suffix",
+    ))
+  }
+}
+
+pub fn is_trait_object_arc(ty: &Type) -> bool {
+  matches!(generic_inner(ty, "Arc"), Some(Type::TraitObject(_)))
+}
+
+pub fn is_trait_object_vec(ty: &Type) -> bool {
+  generic_inner(ty, "Vec").is_some_and(is_trait_object_arc)
+}
+
+fn is_wrapper(ty: &Type, wrapper: &str) -> bool {
+  generic_inner(ty, wrapper).is_some()
+}
+
+fn option_inner(ty: &Type) -> Option<&Type> {
+  generic_inner(ty, "Option")
+}
+
+fn generic_inner<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+  let Type::Path(type_path) = ty else {
+    return None;
+  };
+
+  let segment = type_path.path.segments.last()?;
+
+  let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+    return None;
+  };
+
+  if segment.ident != wrapper {
+    return None;
+  }
+
+  match arguments.args.first() {
+    Some(GenericArgument::Type(inner)) => Some(inner),
+    _ => None,
+  }
+}
@@ -1,63 +1,279 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, Data, DataStruct, DeriveInput, Fields, FieldsNamed};
+use syn::punctuated::Punctuated;
+use syn::{parenthesized, parse_macro_input, Data, DataStruct, DeriveInput, ItemImpl, LitStr, Token, Type};
 
-struct Field {
-  ident: syn::Ident,
-  ty: syn::Type,
-}
-
-const REPOSITORY_SUFFIX: &str = "_repository";
+use crate::field::{Field, Injection};
+use crate::lifecycle::impl_lifecycle;
 
-const SERVICE_SUFFIX: &str = "_service";
+mod field;
+mod lifecycle;
 
-#[proc_macro_derive(Service)]
+#[proc_macro_derive(Service, attributes(profile, conditional_on_property, conditional_on_missing_bean, conditional_on_feature, binds, named, primary, order, scope, inject, config, default, init))]
 pub fn derive_service(input: TokenStream) -> TokenStream {
   impl_derive_service(parse_macro_input!(input as DeriveInput)).unwrap_or_else(|err| err.to_compile_error().into())
 }
 
 fn impl_derive_service(input: DeriveInput) -> syn::Result<TokenStream> {
   let ident = &input.ident;
-  let fields = get_fields(&input)?.iter().map(|field| {
-    let field_ident = &field.ident;
-    let field_ty = &field.ty;
-    let field_name = field_ident.to_string();
-
-    if field_name.ends_with(REPOSITORY_SUFFIX) {
-      return Ok(quote! {
-        #field_ident: context.get_repository()
-      });
+  let fields = get_fields(&input)?;
+  let dependencies = fields.iter().filter_map(Field::dependency);
+  let fields = fields.iter().map(|field| {
+    let member = &field.member;
+    let value = field.quote()?;
+
+    Ok(quote! {
+      #member: #value
+    })
+  }).collect::<syn::Result<Vec<_>>>()?;
+
+  let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+  let is_generic = !input.generics.params.is_empty();
+
+  let profiles = get_profiles(&input)?;
+  let conditions = get_conditions(&input)?;
+  let bindings = get_bindings(&input)?;
+
+  if let Some(attr) = input.attrs.iter().find(|attr| attr.path().is_ident("conditional_on_missing_bean"))
+    && bindings.is_empty()
+  {
+    return Err(syn::Error::new_spanned(attr, "#[conditional_on_missing_bean] requires #[binds(...)]"));
+  }
+
+  let scope = get_scope(&input)?;
+  let name = get_name(&input)?;
+  let primary = input.attrs.iter().any(|attr| attr.path().is_ident("primary"));
+  let order = get_order(&input)?;
+  let binding_name = match &name {
+    Some(name) => quote! { Some(#name) },
+    None => quote! { None },
+  };
+  if let Some(attr) = input.attrs.iter().find(|attr| attr.path().is_ident("primary"))
+    && bindings.is_empty()
+  {
+    return Err(syn::Error::new_spanned(attr, "#[primary] requires #[binds(...)]"));
+  }
+
+  let named_self = name.as_ref().filter(|_| bindings.is_empty() && !is_generic).map(|name| quote! {
+    actix_boot::discovery::inventory::submit! {
+      actix_boot::discovery::BindingComponent {
+        interface: stringify!(#ident),
+        interface_id: std::any::TypeId::of::<#ident>,
+        implementation: concat!(module_path!(), "::", stringify!(#ident)),
+        name: Some(#name),
+        primary: false,
+        profiles: &[#(#profiles),*],
+        conditions: &[#(#conditions),*],
+        bind: |context| context.bind_named_service::<#ident>(#name),
+      }
     }
+  });
 
-    if field_name.ends_with(SERVICE_SUFFIX) {
-      return Ok(quote! {
-        #field_ident: context.get_service()
-      });
+  let bindings = bindings.into_iter().map(|interface| {
+    let bind_named = name.as_ref().map(|name| quote! {
+      context.bind_named::<#interface, #ident>(#name);
+    });
+    let bind_default = match (primary, &name) {
+      (true, _) => Some(quote! { context.bind_primary::<#interface, #ident>(); }),
+      (false, None) => Some(quote! { context.bind::<#interface, #ident>(); }),
+      (false, Some(_)) => None,
+    };
+
+    let submit = (!is_generic).then(|| quote! {
+      actix_boot::discovery::inventory::submit! {
+        actix_boot::discovery::BindingComponent {
+          interface: stringify!(#interface),
+          interface_id: std::any::TypeId::of::<#interface>,
+          implementation: concat!(module_path!(), "::", stringify!(#ident)),
+          name: #binding_name,
+          primary: #primary,
+          profiles: &[#(#profiles),*],
+          conditions: &[#(#conditions),*],
+          bind: |context| {
+            #bind_named
+            #bind_default
+            context.add_implementation::<#interface, #ident>(#order);
+          },
+        }
+      }
+    });
+
+    quote! {
+      impl #impl_generics actix_boot::di::Implements<#interface> for #ident #ty_generics #where_clause {
+        fn upcast(self: std::sync::Arc<Self>) -> std::sync::Arc<#interface> {
+          self
+        }
+      }
+
+      #submit
     }
+  });
 
-    Err(syn::Error::new_spanned(
-      field_ty,
-      "Field must have _repository or _service suffix",
-    ))
-  }).collect::<syn::Result<Vec<_>>>()?;
+  let submit = (!is_generic).then(|| quote! {
+    actix_boot::discovery::inventory::submit! {
+      actix_boot::discovery::Component {
+        name: concat!(module_path!(), "::", stringify!(#ident)),
+        module_path: module_path!(),
+        kind: actix_boot::discovery::ComponentKind::Service,
+        profiles: &[#(#profiles),*],
+        conditions: &[#(#conditions),*],
+        register: actix_boot::discovery::register_service::<#ident>,
+        info: actix_boot::di::ComponentInfo::service::<#ident>,
+      }
+    }
+  });
 
   Ok(quote! {
-    impl actix_boot::service::Service for #ident {
+    impl #impl_generics actix_boot::service::Service for #ident #ty_generics #where_clause {
+      const SCOPE: actix_boot::di::Scope = actix_boot::di::Scope::#scope;
+
       fn new_service(context: &actix_boot::di::DIContext) -> Self {
-        Self {
+        Self::try_new_service(context).unwrap_or_else(|err| panic!("{}", err))
+      }
+
+      fn try_new_service(context: &actix_boot::di::DIContext) -> Result<Self, actix_boot::di::DIError> {
+        Ok(Self {
           #(#fields),*
-        }
+        })
+      }
+
+      fn dependencies() -> Vec<actix_boot::di::Dependency> {
+        vec![#(#dependencies),*]
+      }
+
+      const HAS_INIT: bool = {
+        use actix_boot::service::LifecycleFallback as _;
+        Self::__ACTIX_BOOT_HAS_INIT
+      };
+
+      fn init(&self) -> impl std::future::Future<Output = Result<(), actix_boot::di::BoxError>> + Send {
+        use actix_boot::service::LifecycleFallback as _;
+        Self::__actix_boot_init(self)
+      }
+
+      fn post_construct(&self) {
+        use actix_boot::service::LifecycleFallback as _;
+        Self::__actix_boot_post_construct(self)
+      }
+
+      fn pre_destroy(&self) -> impl std::future::Future<Output = Result<(), actix_boot::di::BoxError>> + Send {
+        use actix_boot::service::LifecycleFallback as _;
+        Self::__actix_boot_pre_destroy(self)
+      }
+    }
+
+    impl #impl_generics actix_boot::di::Injectable for #ident #ty_generics #where_clause {
+      fn try_resolve(context: &actix_boot::di::DIContext) -> Result<std::sync::Arc<Self>, actix_boot::di::DIError> {
+        context.try_get_service::<Self>()
+      }
+
+      fn component_info() -> actix_boot::di::ComponentInfo {
+        actix_boot::di::ComponentInfo::service::<Self>()
       }
     }
 
-    impl actix_boot::di::GetOrCreate for #ident {
+    impl #impl_generics actix_boot::di::GetOrCreate for #ident #ty_generics #where_clause {
       fn get_or_create(ctx: &actix_boot::di::DIContext) -> actix_web::web::Data<Self> {
-        actix_web::web::Data::from(ctx.get_service::<#ident>())
+        actix_web::web::Data::from(ctx.get_service::<Self>())
       }
     }
+
+    #submit
+
+    #named_self
+
+    #(#bindings)*
   }.into())
 }
 
+fn get_bindings(input: &DeriveInput) -> syn::Result<Vec<Type>> {
+  let mut bindings = Vec::new();
+
+  for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("binds")) {
+    bindings.extend(attr.parse_args_with(Punctuated::<Type, Token![,]>::parse_terminated)?);
+  }
+
+  Ok(bindings)
+}
+
+fn get_name(input: &DeriveInput) -> syn::Result<Option<LitStr>> {
+  input.attrs.iter()
+    .find(|attr| attr.path().is_ident("named"))
+    .map(|attr| attr.parse_args())
+    .transpose()
+}
+
+fn get_order(input: &DeriveInput) -> syn::Result<i32> {
+  input.attrs.iter()
+    .find(|attr| attr.path().is_ident("order"))
+    .map(|attr| attr.parse_args::<syn::LitInt>()?.base10_parse())
+    .transpose()
+    .map(Option::unwrap_or_default)
+}
+
+fn get_scope(input: &DeriveInput) -> syn::Result<syn::Ident> {
+  let mut scope = syn::Ident::new("Singleton", proc_macro2::Span::call_site());
+
+  for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("scope")) {
+    let value: syn::Ident = attr.parse_args()?;
+
+    scope = match value.to_string().as_str() {
+      "singleton" => syn::Ident::new("Singleton", value.span()),
+      "prototype" => syn::Ident::new("Prototype", value.span()),
+      "request" => syn::Ident::new("Request", value.span()),
+      _ => return Err(syn::Error::new_spanned(value, "Expected singleton, prototype or request scope")),
+    };
+  }
+
+  Ok(scope)
+}
+
+fn get_profiles(input: &DeriveInput) -> syn::Result<Vec<LitStr>> {
+  let mut profiles = Vec::new();
+
+  for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("profile")) {
+    profiles.extend(attr.parse_args_with(Punctuated::<LitStr, Token![,]>::parse_terminated)?);
+  }
+
+  Ok(profiles)
+}
+
+fn get_conditions(input: &DeriveInput) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+  let mut conditions = Vec::new();
+
+  for attr in &input.attrs {
+    if attr.path().is_ident("conditional_on_property") {
+      let mut args = attr.parse_args_with(Punctuated::<syn::Expr, Token![,]>::parse_terminated)?.into_iter();
+      let path = match args.next() {
+        Some(syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(path), .. })) => path,
+        _ => return Err(syn::Error::new_spanned(attr, "Expected #[conditional_on_property(\"path\", having = \"value\")]")),
+      };
+      let having = match args.next() {
+        None => quote! { None },
+        Some(syn::Expr::Assign(assign)) if matches!(&*assign.left, syn::Expr::Path(left) if left.path.is_ident("having")) => {
+          let value = &assign.right;
+          quote! { Some(#value) }
+        }
+        Some(other) => return Err(syn::Error::new_spanned(other, "Expected having = \"value\"")),
+      };
+
+      conditions.push(quote! {
+        actix_boot::discovery::Condition::OnProperty { path: #path, having: #having }
+      });
+    } else if attr.path().is_ident("conditional_on_missing_bean") {
+      conditions.push(quote! { actix_boot::discovery::Condition::OnMissingBean });
+    } else if attr.path().is_ident("conditional_on_feature") {
+      let feature: LitStr = attr.parse_args()?;
+
+      conditions.push(quote! {
+        actix_boot::discovery::Condition::OnFeature { feature: #feature, enabled: cfg!(feature = #feature) }
+      });
+    }
+  }
+
+  Ok(conditions)
+}
+
 fn get_fields(input: &DeriveInput) -> syn::Result<Vec<Field>> {
   let Data::Struct(DataStruct { ref fields, .. }) = input.data else {
     return Err(syn::Error::new_spanned(
@@ -66,12 +282,66 @@ fn get_fields(input: &DeriveInput) -> syn::Result<Vec<Field>> {
     ));
   };
 
-  let Fields::Named(FieldsNamed { named, .. }) = fields else {
-   return Ok(vec![]);
-  };
-
-  Ok(named.iter().map(|field| Field {
-    ident: field.ident.clone().unwrap(),
+  fields.iter().enumerate().map(|(index, field)| Ok(Field {
+    member: match &field.ident {
+      Some(ident) => syn::Member::Named(ident.clone()),
+      None => syn::Member::Unnamed(index.into()),
+    },
     ty: field.ty.clone(),
-  }).collect())
-}
\ No newline at end of file
+    injection: Injection::parse(field)?,
+  })).collect()
+}
+
+#[proc_macro_attribute]
+pub fn lifecycle(attr: TokenStream, item: TokenStream) -> TokenStream {
+  let _ = parse_macro_input!(attr as syn::parse::Nothing);
+
+  impl_lifecycle(parse_macro_input!(item as ItemImpl))
+    .unwrap_or_else(|err| err.to_compile_error())
+    .into()
+}
+
+#[proc_macro_derive(Registry, attributes(registry))]
+pub fn derive_registry(input: TokenStream) -> TokenStream {
+  impl_derive_registry(parse_macro_input!(input as DeriveInput)).unwrap_or_else(|err| err.to_compile_error().into())
+}
+
+fn impl_derive_registry(input: DeriveInput) -> syn::Result<TokenStream> {
+  let ident = &input.ident;
+  let mut services = Vec::new();
+  let mut repositories = Vec::new();
+
+  for attr in input.attrs.iter().filter(|attr| attr.path().is_ident("registry")) {
+    attr.parse_nested_meta(|meta| {
+      let content;
+      parenthesized!(content in meta.input);
+      let types = Punctuated::<Type, Token![,]>::parse_terminated(&content)?;
+
+      if meta.path.is_ident("services") {
+        services.extend(types);
+        Ok(())
+      } else if meta.path.is_ident("repositories") {
+        repositories.extend(types);
+        Ok(())
+      } else {
+        Err(meta.error("Expected services(...) or repositories(...)"))
+      }
+    })?;
+  }
+
+  Ok(quote! {
+    impl actix_boot::registry::Registry for #ident {
+      fn register_all(configurer: &mut actix_boot::server::config::ApplicationServerConfigurer) {
+        #(actix_boot::server::config::registry::ServiceRegistrator::register::<#services>(configurer);)*
+        #(actix_boot::server::config::registry::RepositoryRegistrator::register::<#repositories>(configurer);)*
+      }
+
+      fn components() -> Vec<actix_boot::di::ComponentInfo> {
+        vec![
+          #(actix_boot::di::ComponentInfo::service::<#services>(),)*
+          #(actix_boot::di::ComponentInfo::repository::<#repositories>(),)*
+        ]
+      }
+    }
+  }.into())
+}
@@ -0,0 +1,99 @@
+use quote::quote;
+use syn::{ImplItem, ItemImpl};
+
+#[derive(Default)]
+struct Hooks {
+  init: Option<syn::Ident>,
+  post_construct: Option<syn::Ident>,
+  pre_destroy: Option<syn::Ident>,
+}
+
+pub fn impl_lifecycle(mut item: ItemImpl) -> syn::Result<proc_macro2::TokenStream> {
+  if let Some((_, path, _)) = &item.trait_ {
+    return Err(syn::Error::new_spanned(path, "#[lifecycle] must be placed on an inherent impl block"));
+  }
+
+  let mut hooks = Hooks::default();
+
+  for impl_item in &mut item.items {
+    let ImplItem::Fn(function) = impl_item else {
+      continue;
+    };
+
+    let mut attrs = Vec::new();
+
+    for attr in function.attrs.drain(..) {
+      let hook = if attr.path().is_ident("init") {
+        &mut hooks.init
+      } else if attr.path().is_ident("post_construct") {
+        &mut hooks.post_construct
+      } else if attr.path().is_ident("pre_destroy") {
+        &mut hooks.pre_destroy
+      } else {
+        attrs.push(attr);
+        continue;
+      };
+
+      attr.meta.require_path_only()?;
+
+      if hook.is_some() {
+        return Err(syn::Error::new_spanned(attr, "Lifecycle method is already declared"));
+      }
+
+      *hook = Some(function.sig.ident.clone());
+    }
+
+    function.attrs = attrs;
+  }
+
+  let self_ty = &item.self_ty;
+  let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+  let is_generic = !item.generics.params.is_empty();
+
+  let singleton_only = |hook: &Option<syn::Ident>, message: &str| hook.as_ref().filter(|_| !is_generic).map(|_| quote! {
+    const _: () = assert!(
+      matches!(<#self_ty as actix_boot::service::Service>::SCOPE, actix_boot::di::Scope::Singleton),
+      #message,
+    );
+  });
+  let init_scope = singleton_only(&hooks.init, "#[init] is only supported on singleton services");
+  let pre_destroy_scope = singleton_only(&hooks.pre_destroy, "#[pre_destroy] is only supported on singleton services");
+
+  let init = hooks.init.map(|method| quote! {
+    #[doc(hidden)]
+    const __ACTIX_BOOT_HAS_INIT: bool = true;
+
+    #[doc(hidden)]
+    fn __actix_boot_init(&self) -> impl std::future::Future<Output = Result<(), actix_boot::di::BoxError>> + Send {
+      async move { Self::#method(self).await.map_err(Into::into) }
+    }
+  });
+  let post_construct = hooks.post_construct.map(|method| quote! {
+    #[doc(hidden)]
+    fn __actix_boot_post_construct(&self) {
+      Self::#method(self)
+    }
+  });
+  let pre_destroy = hooks.pre_destroy.map(|method| quote! {
+    #[doc(hidden)]
+    fn __actix_boot_pre_destroy(&self) -> impl std::future::Future<Output = Result<(), actix_boot::di::BoxError>> + Send {
+      async move { Self::#method(self).await.map_err(Into::into) }
+    }
+  });
+
+  Ok(quote! {
+    #item
+
+    impl #impl_generics #self_ty #where_clause {
+      #init
+
+      #post_construct
+
+      #pre_destroy
+    }
+
+    #init_scope
+
+    #pre_destroy_scope
+  })
+}
@@ -1,10 +1,15 @@
-use actix_boot::di::GetOrCreate;
+use actix_boot::controller::macros::controller;
+use actix_boot::registry::derive::Registry;
 use actix_boot::repository::macros::repository;
 use actix_boot::server::ApplicationServer;
-use actix_web::web::{Data, Json};
+use actix_boot::service::derive::Service;
+use actix_boot::validation::{Valid, Validate};
+use actix_web::web::{Data, Json, Query};
 use actix_web::{Responder, get, main};
 use entity::post;
 use sea_orm::entity::prelude::*;
+use serde::Deserialize;
+use std::sync::Arc;
 
 pub mod entity;
 
@@ -87,16 +92,20 @@ pub trait PostRepositoryBase {
   ) -> Result<Option<post::Model>, sea_orm::DbErr>;
 }
 
+#[derive(Registry)]
+#[registry(repositories(PostRepository))]
+struct AppRegistry;
+
 #[main]
 async fn main() -> std::io::Result<()> {
-  ApplicationServer::start(|app, _| {
-    app.configure(|app, ctx| {
-      app.app_data(PostRepository::get_or_create(ctx));
+  ApplicationServer::builder()
+    .registry::<AppRegistry>()
+    .controller::<PostController>()
+    .configure(|app, _| {
       app.service(test);
-      app.service(paginated_posts);
-    });
-  })
-  .await
+    })
+    .run()
+    .await
 }
 
 #[get("/test")]
@@ -109,12 +118,40 @@ async fn test(post_repository: Data<PostRepository>) -> impl Responder {
   )
 }
 
-#[get("/posts/paginated")]
-async fn paginated_posts(post_repository: Data<PostRepository>) -> impl Responder {
-  let page_result = post_repository
-    .find_all_by_text_paginate("haha", 2, 10)
-    .await
-    .unwrap();
+#[derive(Deserialize, Validate)]
+struct SearchPosts {
+  #[validate(length(min = 1, max = 255))]
+  text: String,
+  #[validate(range(min = 1, max = 100))]
+  limit: u64,
+}
 
-  Json(page_result)
+#[derive(Service)]
+pub struct PostController {
+  post_repository: Arc<PostRepository>,
+}
+
+#[controller("/posts")]
+impl PostController {
+  #[get("/paginated")]
+  async fn paginated(&self) -> impl Responder {
+    let page_result = self
+      .post_repository
+      .find_all_by_text_paginate("haha", 2, 10)
+      .await
+      .unwrap();
+
+    Json(page_result)
+  }
+
+  #[get("/search")]
+  async fn search(&self, search: Valid<Query<SearchPosts>>) -> impl Responder {
+    Json(
+      self
+        .post_repository
+        .find_all_by_text_limit(&search.text, search.limit)
+        .await
+        .unwrap(),
+    )
+  }
 }
@@ -36,9 +36,9 @@ impl Display for LogLevel {
   }
 }
 
-impl Into<log::LevelFilter> for LogLevel {
-  fn into(self) -> LevelFilter {
-    match self {
+impl From<LogLevel> for LevelFilter {
+  fn from(level: LogLevel) -> Self {
+    match level {
       LogLevel::Off => log::LevelFilter::Off,
       LogLevel::Error => log::LevelFilter::Error,
       LogLevel::Warn => log::LevelFilter::Warn,
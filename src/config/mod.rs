@@ -31,7 +31,7 @@ impl SnokeConfig {
       let default_value = caps.get(2).map(|m| m.as_str());
 
       env::var(var_name)
-        .unwrap_or_else(|_| default_value.expect(&format!("You need to specify {}", var_name)).to_string())
+        .unwrap_or_else(|_| default_value.unwrap_or_else(|| panic!("You need to specify {}", var_name)).to_string())
     }).to_string()
   }
 }
\ No newline at end of file
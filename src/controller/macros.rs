@@ -0,0 +1 @@
+pub use actix_boot_controller_macros::controller;
\ No newline at end of file
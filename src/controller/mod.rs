@@ -0,0 +1,13 @@
+use actix_web::web::ServiceConfig;
+use crate::di::{DIContext, Dependency};
+use crate::service::Service;
+
+pub mod macros;
+
+pub trait Controller: Service {
+  fn configure(service_config: &mut ServiceConfig, context: &DIContext);
+
+  fn handler_dependencies() -> Vec<Dependency> {
+    Vec::new()
+  }
+}
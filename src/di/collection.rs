@@ -0,0 +1,70 @@
+use std::any::{type_name, Any, TypeId};
+use std::sync::Arc;
+use crate::di::{resolve_binding, Binding, ComponentInfo, DIContext, DIError, Implements};
+use crate::service::Service;
+
+pub(crate) struct CollectionEntry {
+  order: i32,
+  name: &'static str,
+  pub(crate) info: fn() -> ComponentInfo,
+  binding: Arc<dyn Any + Send + Sync>,
+}
+
+impl DIContext {
+  pub fn add_implementation<I, T>(&self, order: i32)
+  where
+    I: ?Sized + 'static,
+    T: Implements<I> + Service,
+  {
+    let entry = CollectionEntry {
+      order,
+      name: type_name::<T>(),
+      info: ComponentInfo::service::<T>,
+      binding: Arc::new(Binding::<I> {
+        resolve: resolve_binding::<I, T>,
+      }),
+    };
+
+    let mut collections_guard = self.collections.write().expect("Poisoned lock");
+    let entries = collections_guard.entry(TypeId::of::<I>()).or_default();
+
+    entries.retain(|existing| existing.name != entry.name);
+    entries.push(entry);
+    entries.sort_by(|a, b| a.order.cmp(&b.order).then(a.name.cmp(b.name)));
+  }
+
+  pub fn try_get_all<I>(&self) -> Result<Vec<Arc<I>>, DIError>
+  where
+    I: ?Sized + 'static,
+  {
+    let resolvers = self.collections
+      .read()
+      .expect("Poisoned lock")
+      .get(&TypeId::of::<I>())
+      .map(|entries| {
+        entries.iter()
+          .filter_map(|entry| entry.binding.downcast_ref::<Binding<I>>())
+          .map(|binding| binding.resolve)
+          .collect::<Vec<_>>()
+      })
+      .unwrap_or_default();
+
+    resolvers.into_iter().map(|resolve| resolve(self)).collect()
+  }
+
+  pub fn get_all<I>(&self) -> Vec<Arc<I>>
+  where
+    I: ?Sized + 'static,
+  {
+    self.try_get_all().unwrap_or_else(|err| panic!("{}", err))
+  }
+
+  pub(crate) fn collection_infos(&self, interface: TypeId) -> Vec<fn() -> ComponentInfo> {
+    self.collections
+      .read()
+      .expect("Poisoned lock")
+      .get(&interface)
+      .map(|entries| entries.iter().map(|entry| entry.info).collect())
+      .unwrap_or_default()
+  }
+}
@@ -0,0 +1,135 @@
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DIError {
+  MissingBinding {
+    interface: &'static str,
+  },
+  AmbiguousBinding {
+    interface: &'static str,
+    candidates: Vec<String>,
+  },
+  MissingNamed {
+    name: String,
+    ty: &'static str,
+  },
+  Config {
+    path: String,
+    message: String,
+  },
+  OutOfRequestScope {
+    service: &'static str,
+  },
+  Cycle {
+    path: Vec<String>,
+  },
+  ScopeViolation {
+    component: String,
+    field: &'static str,
+    dependency: String,
+  },
+  Unresolvable {
+    component: String,
+    field: &'static str,
+    cause: Box<DIError>,
+  },
+  Validation(Vec<DIError>),
+  Init {
+    service: String,
+    message: String,
+  },
+  Destroy {
+    service: String,
+    message: String,
+  },
+  MissingDatasource {
+    name: String,
+  },
+  MissingInstance {
+    ty: &'static str,
+  },
+  MissingComponent {
+    ty: &'static str,
+  },
+  DisabledComponent {
+    ty: &'static str,
+  },
+  PendingInstance {
+    ty: &'static str,
+  },
+  NotShared {
+    ty: &'static str,
+  },
+  NotInitialized {
+    service: String,
+  },
+}
+
+impl Display for DIError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      DIError::MissingBinding { interface } => {
+        write!(f, "No binding registered for {}", interface)
+      }
+      DIError::AmbiguousBinding { interface, candidates } => {
+        write!(f, "Multiple candidates for {}: {}; mark one #[primary] or inject by name", interface, candidates.join(", "))
+      }
+      DIError::MissingNamed { name, ty } => {
+        write!(f, "No component named '{}' registered for {}", name, ty)
+      }
+      DIError::Config { path, message } => {
+        write!(f, "Invalid config value '{}': {}", path, message)
+      }
+      DIError::OutOfRequestScope { service } => {
+        write!(f, "{} is request scoped and can only be resolved while handling a request", service)
+      }
+      DIError::Cycle { path } => {
+        write!(f, "cycle: {}", path.join(" -> "))
+      }
+      DIError::ScopeViolation { component, field, dependency } => {
+        write!(f, "{}.{}: singleton depends on request scoped {}", component, field, dependency)
+      }
+      DIError::Unresolvable { component, field, cause } => {
+        write!(f, "{}.{}: {}", component, field, cause)
+      }
+      DIError::Init { service, message } => {
+        write!(f, "Failed to initialize {}: {}", service, message)
+      }
+      DIError::Destroy { service, message } => {
+        write!(f, "Failed to destroy {}: {}", service, message)
+      }
+      DIError::MissingDatasource { name } => {
+        write!(f, "Datasource '{}' is not registered", name)
+      }
+      DIError::MissingInstance { ty } => {
+        write!(f, "No instance registered for {}", ty)
+      }
+      DIError::MissingComponent { ty } => {
+        write!(f, "No component, binding or instance registered for {}", ty)
+      }
+      DIError::DisabledComponent { ty } => {
+        write!(f, "{} is disabled by its profile or conditions", ty)
+      }
+      DIError::PendingInstance { ty } => {
+        write!(f, "Instance of {} is created by an async factory and is not initialized yet", ty)
+      }
+      DIError::NotShared { ty } => {
+        write!(f, "Cannot defer resolution of {}: DIContext must be shared with DIContext::into_shared first", ty)
+      }
+      DIError::NotInitialized { service } => {
+        write!(f, "{} has an init hook that did not run: it must be reachable from a startup root", service)
+      }
+      DIError::Validation(errors) => {
+        write!(f, "Dependency graph validation failed with {} error(s):", errors.len())?;
+
+        for error in errors {
+          write!(f, "\n  - {}", error)?;
+        }
+
+        Ok(())
+      }
+    }
+  }
+}
+
+impl std::error::Error for DIError {}
@@ -0,0 +1,219 @@
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+use std::path::Path;
+use serde::Serialize;
+use crate::di::resolution::short_type_name;
+use crate::di::{ComponentInfo, DIContext, Dependency, DependencyTarget};
+use crate::discovery::ComponentKind;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyGraph {
+  pub nodes: Vec<GraphNode>,
+  pub edges: Vec<GraphEdge>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphNode {
+  pub id: String,
+  pub label: String,
+  pub kind: &'static str,
+  pub used: bool,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GraphEdge {
+  pub from: String,
+  pub to: String,
+  pub field: &'static str,
+  pub optional: bool,
+  pub deferred: bool,
+  pub ambiguous: bool,
+}
+
+struct GraphBuilder<'a> {
+  context: &'a DIContext,
+  nodes: BTreeMap<String, GraphNode>,
+  edges: Vec<GraphEdge>,
+  visited: HashSet<String>,
+}
+
+impl GraphBuilder<'_> {
+  fn component(&mut self, info: ComponentInfo) -> String {
+    let id = (info.name)().to_string();
+
+    self.node(&id, info.short_name(), kind_name(&info));
+
+    if self.visited.insert(id.clone()) {
+      for dependency in (info.dependencies)() {
+        self.dependency(&id, dependency);
+      }
+    }
+
+    id
+  }
+
+  fn dependency(&mut self, from: &str, dependency: Dependency) {
+    let to = match dependency.target {
+      DependencyTarget::Component(target) => self.component(target()),
+      DependencyTarget::Binding { type_id, name } => {
+        let id = name().to_string();
+        self.node(&id, short_type_name(&id), "binding");
+
+        for (candidate, ambiguous) in self.context.binding_candidates(type_id()) {
+          let target = self.component((candidate.info)());
+          self.edge(&id, &target, "binds", &dependency).ambiguous = ambiguous;
+        }
+
+        id
+      }
+      DependencyTarget::Collection { type_id, name } => {
+        let id = format!("Vec<{}>", name());
+        self.node(&id, short_type_name(&id), "collection");
+
+        for target in self.context.collection_infos(type_id()) {
+          let target = self.component(target());
+          self.edge(&id, &target, "implements", &dependency);
+        }
+
+        id
+      }
+      DependencyTarget::Named { ty, name, .. } => {
+        let id = format!("{}#{}", ty(), name);
+        self.node(&id, format!("{} \"{}\"", short_type_name(ty()), name), "named");
+        id
+      }
+      DependencyTarget::Instance { ty, .. } => {
+        let id = ty().to_string();
+        self.node(&id, short_type_name(&id), "instance");
+        id
+      }
+      DependencyTarget::Config { path } => {
+        let id = format!("config:{}", path);
+        self.node(&id, path.to_string(), "config");
+        id
+      }
+      DependencyTarget::Injected { type_id, name } => {
+        let target = self.context.binding_info(type_id())
+          .map(|target| target())
+          .or_else(|| self.context.component_info(type_id()));
+
+        match target {
+          Some(target) => self.component(target),
+          None => {
+            let id = name().to_string();
+            self.node(&id, short_type_name(&id), "instance");
+            id
+          }
+        }
+      }
+    };
+
+    self.edge(from, &to, dependency.field, &dependency);
+  }
+
+  fn node(&mut self, id: &str, label: String, kind: &'static str) {
+    self.nodes.entry(id.to_string()).or_insert_with(|| GraphNode {
+      id: id.to_string(),
+      label,
+      kind,
+      used: false,
+    });
+  }
+
+  fn edge(&mut self, from: &str, to: &str, field: &'static str, dependency: &Dependency) -> &mut GraphEdge {
+    if let Some(node) = self.nodes.get_mut(to) {
+      node.used = true;
+    }
+
+    self.edges.push(GraphEdge {
+      from: from.to_string(),
+      to: to.to_string(),
+      field,
+      optional: dependency.optional,
+      deferred: dependency.deferred,
+      ambiguous: false,
+    });
+
+    self.edges.last_mut().expect("Edge was just pushed")
+  }
+}
+
+fn kind_name(info: &ComponentInfo) -> &'static str {
+  match info.kind {
+    ComponentKind::Service => "service",
+    ComponentKind::Repository => "repository",
+    ComponentKind::Controller => "controller",
+  }
+}
+
+impl DependencyGraph {
+  pub fn to_dot(&self) -> String {
+    let mut dot = String::from("digraph dependencies {\n  rankdir=LR;\n");
+
+    for node in &self.nodes {
+      let shape = match node.kind {
+        "binding" | "collection" => "diamond",
+        "config" => "note",
+        "instance" | "named" => "ellipse",
+        _ => "box",
+      };
+
+      let _ = writeln!(dot, "  {:?} [label={:?}, shape={}];", node.id, node.label, shape);
+    }
+
+    for edge in &self.edges {
+      let style = if edge.deferred { "dashed" } else if edge.optional { "dotted" } else { "solid" };
+      let color = if edge.ambiguous { "red" } else { "black" };
+      let _ = writeln!(dot, "  {:?} -> {:?} [label={:?}, style={}, color={}];", edge.from, edge.to, edge.field, style, color);
+    }
+
+    dot.push_str("}\n");
+    dot
+  }
+
+  pub fn to_json(&self) -> String {
+    serde_json::to_string_pretty(self).expect("Failed to serialize dependency graph")
+  }
+
+  pub fn write_to(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+    let path = path.as_ref();
+
+    let content = match path.extension().and_then(|extension| extension.to_str()) {
+      Some("json") => self.to_json(),
+      _ => self.to_dot(),
+    };
+
+    std::fs::write(path, content)
+  }
+}
+
+impl DIContext {
+  pub fn dependency_graph(&self, roots: &[ComponentInfo]) -> DependencyGraph {
+    let mut builder = GraphBuilder {
+      context: self,
+      nodes: BTreeMap::new(),
+      edges: Vec::new(),
+      visited: HashSet::new(),
+    };
+
+    let mut registered = self.components.read().expect("Poisoned lock").values().copied().collect::<Vec<_>>();
+    registered.sort_by_key(|info| (info.name)());
+
+    for info in roots {
+      let id = builder.component(*info);
+
+      if let Some(node) = builder.nodes.get_mut(&id) {
+        node.used = true;
+      }
+    }
+
+    for info in registered {
+      builder.component(info);
+    }
+
+    DependencyGraph {
+      nodes: builder.nodes.into_values().collect(),
+      edges: builder.edges,
+    }
+  }
+}
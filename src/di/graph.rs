@@ -0,0 +1,318 @@
+use std::any::{type_name, Any, TypeId};
+use std::collections::HashSet;
+use std::sync::Arc;
+use sea_orm::DatabaseConnection;
+use crate::controller::Controller;
+use crate::di::resolution::short_type_name;
+use crate::di::lifecycle::{destroy_service, init_nothing, init_service};
+use crate::di::{DIContext, DIError, InitFuture, Scope};
+use crate::discovery::ComponentKind;
+use crate::service::Service;
+
+#[derive(Clone, Copy)]
+pub struct ComponentInfo {
+  pub type_id: fn() -> TypeId,
+  pub name: fn() -> &'static str,
+  pub kind: ComponentKind,
+  pub scope: Scope,
+  pub has_init: bool,
+  pub dependencies: fn() -> Vec<Dependency>,
+  pub init: fn(&DIContext) -> InitFuture<'_>,
+  pub destroy: fn(&DIContext) -> InitFuture<'_>,
+  pub resolve: fn(&DIContext) -> Result<Arc<dyn Any + Send + Sync>, DIError>,
+}
+
+#[derive(Clone, Copy)]
+pub struct Dependency {
+  pub field: &'static str,
+  pub target: DependencyTarget,
+  pub optional: bool,
+  pub deferred: bool,
+}
+
+#[derive(Clone, Copy)]
+pub enum DependencyTarget {
+  Component(fn() -> ComponentInfo),
+  Binding {
+    type_id: fn() -> TypeId,
+    name: fn() -> &'static str,
+  },
+  Collection {
+    type_id: fn() -> TypeId,
+    name: fn() -> &'static str,
+  },
+  Instance {
+    type_id: fn() -> TypeId,
+    ty: fn() -> &'static str,
+  },
+  Named {
+    type_id: fn() -> TypeId,
+    ty: fn() -> &'static str,
+    name: &'static str,
+  },
+  Config {
+    path: &'static str,
+  },
+  Injected {
+    type_id: fn() -> TypeId,
+    name: fn() -> &'static str,
+  },
+}
+
+fn resolve_service<S>(context: &DIContext) -> Result<Arc<dyn Any + Send + Sync>, DIError>
+where
+  S: Service,
+{
+  Ok(Arc::new(context.try_get_service::<S>()?))
+}
+
+fn resolve_repository<R>(context: &DIContext) -> Result<Arc<dyn Any + Send + Sync>, DIError>
+where
+  R: From<DatabaseConnection> + Send + Sync + 'static,
+{
+  Ok(Arc::new(context.try_get_repository::<R>()?))
+}
+
+fn controller_dependencies<C>() -> Vec<Dependency>
+where
+  C: Controller,
+{
+  C::dependencies().into_iter().chain(C::handler_dependencies()).collect()
+}
+
+impl ComponentInfo {
+  pub fn service<S>() -> Self
+  where
+    S: Service,
+  {
+    Self {
+      type_id: TypeId::of::<S>,
+      name: type_name::<S>,
+      kind: ComponentKind::Service,
+      scope: S::SCOPE,
+      has_init: S::HAS_INIT,
+      dependencies: S::dependencies,
+      init: init_service::<S>,
+      destroy: destroy_service::<S>,
+      resolve: resolve_service::<S>,
+    }
+  }
+
+  pub fn controller<C>() -> Self
+  where
+    C: Controller,
+  {
+    Self {
+      kind: ComponentKind::Controller,
+      dependencies: controller_dependencies::<C>,
+      ..Self::service::<C>()
+    }
+  }
+
+  pub fn repository<R>() -> Self
+  where
+    R: From<DatabaseConnection> + Send + Sync + 'static,
+  {
+    Self {
+      type_id: TypeId::of::<R>,
+      name: type_name::<R>,
+      kind: ComponentKind::Repository,
+      scope: Scope::Singleton,
+      has_init: false,
+      dependencies: Vec::new,
+      init: init_nothing,
+      destroy: init_nothing,
+      resolve: resolve_repository::<R>,
+    }
+  }
+
+  pub fn short_name(&self) -> String {
+    short_type_name((self.name)())
+  }
+}
+
+struct Validation<'a> {
+  context: &'a DIContext,
+  stack: Vec<ComponentInfo>,
+  visited: HashSet<TypeId>,
+  deferred: Vec<ComponentInfo>,
+  errors: Vec<DIError>,
+}
+
+impl Validation<'_> {
+  fn visit(&mut self, info: ComponentInfo) {
+    let type_id = (info.type_id)();
+
+    if let Some(index) = self.stack.iter().position(|entry| (entry.type_id)() == type_id) {
+      let path = self.stack[index..].iter()
+        .chain(std::iter::once(&info))
+        .map(ComponentInfo::short_name)
+        .collect();
+
+      self.errors.push(DIError::Cycle { path });
+      return;
+    }
+
+    if !self.visited.insert(type_id) || self.context.is_overridden(type_id) || self.context.is_disabled(type_id) {
+      return;
+    }
+
+    self.context.register_component(info);
+    self.stack.push(info);
+
+    for dependency in (info.dependencies)() {
+      self.visit_dependency(&info, dependency);
+    }
+
+    self.stack.pop();
+  }
+
+  fn visit_dependency(&mut self, info: &ComponentInfo, dependency: Dependency) {
+    let target = match dependency.target {
+      DependencyTarget::Component(target) => target(),
+      DependencyTarget::Binding { type_id, name } => match self.context.binding_info(type_id()) {
+        _ if self.context.is_overridden(type_id()) => return,
+        Some(_) if self.check_unambiguous(info, &dependency, type_id(), name()) => return,
+        Some(target) => target(),
+        None => {
+          if !dependency.optional && !self.context.has_primary(type_id()) {
+            self.unresolvable(info, &dependency, DIError::MissingBinding { interface: name() });
+          }
+          return;
+        }
+      },
+      DependencyTarget::Collection { type_id, .. } => {
+        for target in self.context.collection_infos(type_id()) {
+          self.visit_target(info, &dependency, target());
+        }
+        return;
+      }
+      DependencyTarget::Instance { type_id, ty } => {
+        if !dependency.optional && !self.context.has_instance(type_id()) {
+          self.unresolvable(info, &dependency, DIError::MissingInstance { ty: ty() });
+        }
+        return;
+      }
+      DependencyTarget::Named { type_id, ty, name } => {
+        if let Some(target) = self.context.named_info(type_id(), name) {
+          target()
+        } else {
+          if !dependency.optional && !self.context.has_named(type_id(), name) {
+            self.unresolvable(info, &dependency, DIError::MissingNamed {
+              name: name.to_string(),
+              ty: ty(),
+            });
+          }
+          return;
+        }
+      }
+      DependencyTarget::Injected { type_id, name } => {
+        let type_id = type_id();
+
+        if self.context.is_overridden(type_id) || self.context.has_instance(type_id) || self.context.has_primary(type_id) {
+          return;
+        }
+
+        if self.context.binding_info(type_id).is_some() && self.check_unambiguous(info, &dependency, type_id, name()) {
+          return;
+        }
+
+        match self.context.binding_info(type_id).map(|target| target()).or_else(|| self.context.component_info(type_id)) {
+          Some(target) => target,
+          None => {
+            if !dependency.optional {
+              self.unresolvable(info, &dependency, DIError::MissingComponent { ty: name() });
+            }
+            return;
+          }
+        }
+      }
+      DependencyTarget::Config { path } => {
+        if !dependency.optional && !self.context.has_config(path) {
+          self.unresolvable(info, &dependency, DIError::Config {
+            path: path.to_string(),
+            message: "value is missing".to_string(),
+          });
+        }
+        return;
+      }
+    };
+
+    self.visit_target(info, &dependency, target);
+  }
+
+  fn visit_target(&mut self, info: &ComponentInfo, dependency: &Dependency, target: ComponentInfo) {
+    if self.context.is_disabled((target.type_id)()) && !self.context.is_overridden((target.type_id)()) {
+      if !dependency.optional {
+        self.unresolvable(info, dependency, DIError::DisabledComponent { ty: (target.name)() });
+      }
+      return;
+    }
+
+    if dependency.deferred {
+      self.deferred.push(target);
+      return;
+    }
+
+    if info.scope == Scope::Singleton && target.scope == Scope::Request {
+      self.errors.push(DIError::ScopeViolation {
+        component: info.short_name(),
+        field: dependency.field,
+        dependency: target.short_name(),
+      });
+    }
+
+    self.visit(target);
+  }
+
+  fn check_unambiguous(&mut self, info: &ComponentInfo, dependency: &Dependency, interface: TypeId, name: &'static str) -> bool {
+    match self.context.check_unambiguous(interface, name) {
+      Ok(()) => false,
+      Err(cause) => {
+        self.unresolvable(info, dependency, cause);
+        true
+      }
+    }
+  }
+
+  fn unresolvable(&mut self, info: &ComponentInfo, dependency: &Dependency, cause: DIError) {
+    self.errors.push(DIError::Unresolvable {
+      component: info.short_name(),
+      field: dependency.field,
+      cause: Box::new(cause),
+    });
+  }
+}
+
+impl DIContext {
+  pub fn validate(&self, roots: &[ComponentInfo]) -> Result<(), DIError> {
+    let mut validation = Validation {
+      context: self,
+      stack: Vec::new(),
+      visited: HashSet::new(),
+      deferred: roots.iter().rev().copied().collect(),
+      errors: Vec::new(),
+    };
+
+    while let Some(info) = validation.deferred.pop() {
+      validation.visit(info);
+    }
+
+    let mut unscheduled = self.components
+      .read()
+      .expect("Poisoned lock")
+      .values()
+      .filter(|info| info.has_init && !validation.visited.contains(&(info.type_id)()) && !self.is_overridden((info.type_id)()))
+      .map(ComponentInfo::short_name)
+      .collect::<Vec<_>>();
+
+    unscheduled.sort();
+    validation.errors.extend(unscheduled.into_iter().map(|service| DIError::NotInitialized { service }));
+
+    if validation.errors.is_empty() {
+      Ok(())
+    } else {
+      Err(DIError::Validation(validation.errors))
+    }
+  }
+}
@@ -0,0 +1,126 @@
+use std::any::{type_name, TypeId};
+use std::future::{ready, Ready};
+use std::ops::Deref;
+use std::sync::Arc;
+use actix_web::dev::Payload;
+use actix_web::error::ErrorInternalServerError;
+use actix_web::web::Data;
+use actix_web::{FromRequest, HttpRequest};
+use serde::de::DeserializeOwned;
+use crate::di::{ComponentInfo, DIContext, DIError, RequestScope};
+
+pub struct Inject<T: ?Sized>(pub Arc<T>);
+
+impl<T: ?Sized> Inject<T> {
+  pub fn into_inner(self) -> Arc<T> {
+    self.0
+  }
+}
+
+impl<T: ?Sized> Deref for Inject<T> {
+  type Target = T;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<T> FromRequest for Inject<T>
+where
+  T: ?Sized + Send + Sync + 'static,
+{
+  type Error = actix_web::Error;
+  type Future = Ready<Result<Self, Self::Error>>;
+
+  fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+    let Some(context) = req.app_data::<Data<DIContext>>() else {
+      return ready(Err(ErrorInternalServerError(format!(
+        "DIContext is not registered as app data, cannot resolve {}",
+        type_name::<T>()
+      ))));
+    };
+
+    let scope = RequestScope::from_request(req);
+    let _guard = scope.enter();
+
+    ready(context.try_resolve::<T>().map(Inject).map_err(ErrorInternalServerError))
+  }
+}
+
+impl DIContext {
+  pub fn try_resolve<T>(&self) -> Result<Arc<T>, DIError>
+  where
+    T: ?Sized + Send + Sync + 'static,
+  {
+    let type_id = TypeId::of::<T>();
+
+    if let Some(instance) = self.overridden::<T>() {
+      return Ok(instance);
+    }
+
+    if self.has_instance(type_id) {
+      return self.try_get_instance();
+    }
+
+    if self.has_primary(type_id) || self.binding_info(type_id).is_some() {
+      return self.try_get_binding();
+    }
+
+    if self.is_disabled(type_id) {
+      return Err(DIError::DisabledComponent { ty: type_name::<T>() });
+    }
+
+    let info = self.component_info(type_id).ok_or(DIError::MissingComponent { ty: type_name::<T>() })?;
+
+    Ok((info.resolve)(self)?
+      .downcast_ref::<Arc<T>>()
+      .cloned()
+      .expect("Failed to downcast component"))
+  }
+
+  pub fn resolve<T>(&self) -> Arc<T>
+  where
+    T: ?Sized + Send + Sync + 'static,
+  {
+    self.try_resolve().unwrap_or_else(|err| panic!("{}", err))
+  }
+
+  pub fn register_config<T>(&self, path: &str)
+  where
+    T: DeserializeOwned + Send + Sync + 'static,
+  {
+    self.register_instance(Arc::new(self.get_config::<T>(path)));
+  }
+
+  pub(crate) fn register_component(&self, info: ComponentInfo) {
+    if self.is_disabled((info.type_id)()) {
+      return;
+    }
+
+    self.components
+      .write()
+      .expect("Poisoned lock")
+      .entry((info.type_id)())
+      .or_insert(info);
+  }
+
+  pub(crate) fn disable_component(&self, type_id: TypeId) {
+    self.components.write().expect("Poisoned lock").remove(&type_id);
+    self.disabled.write().expect("Poisoned lock").insert(type_id);
+  }
+
+  pub(crate) fn is_disabled(&self, type_id: TypeId) -> bool {
+    self.disabled
+      .read()
+      .expect("Poisoned lock")
+      .contains(&type_id)
+  }
+
+  pub(crate) fn component_info(&self, type_id: TypeId) -> Option<ComponentInfo> {
+    self.components
+      .read()
+      .expect("Poisoned lock")
+      .get(&type_id)
+      .copied()
+  }
+}
@@ -0,0 +1,163 @@
+use std::any::{type_name, Any, TypeId};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::{Arc, OnceLock};
+use crate::di::resolution::short_type_name;
+use crate::di::{BoxError, DIContext, DIError};
+
+type Instance = Arc<dyn Any + Send + Sync>;
+
+type InstanceFactory = Box<dyn Fn(&DIContext) -> Instance + Send + Sync>;
+
+type AsyncInstanceFactory = Box<dyn Fn(&DIContext) -> Pin<Box<dyn Future<Output = Result<Instance, BoxError>> + Send>> + Send + Sync>;
+
+enum InstanceSource {
+  Provided,
+  Factory(InstanceFactory),
+  AsyncFactory(AsyncInstanceFactory),
+}
+
+pub(crate) struct InstanceEntry {
+  ty: &'static str,
+  instance: OnceLock<Instance>,
+  source: InstanceSource,
+}
+
+impl InstanceEntry {
+  fn resolve(&self, context: &DIContext) -> Result<Instance, DIError> {
+    if let Some(instance) = self.instance.get() {
+      return Ok(instance.clone());
+    }
+
+    match &self.source {
+      InstanceSource::Factory(factory) => Ok(self.instance.get_or_init(|| factory(context)).clone()),
+      InstanceSource::AsyncFactory(_) => Err(DIError::PendingInstance { ty: self.ty }),
+      InstanceSource::Provided => unreachable!("Provided instance must be initialized"),
+    }
+  }
+}
+
+impl DIContext {
+  pub fn register_instance<T>(&self, instance: Arc<T>)
+  where
+    T: ?Sized + Send + Sync + 'static,
+  {
+    let entry = InstanceEntry {
+      ty: type_name::<T>(),
+      instance: OnceLock::from(Arc::new(instance) as Instance),
+      source: InstanceSource::Provided,
+    };
+
+    self.insert_instance::<T>(entry);
+  }
+
+  pub fn register_factory<T, F>(&self, factory: F)
+  where
+    T: Send + Sync + 'static,
+    F: Fn(&DIContext) -> T + Send + Sync + 'static,
+  {
+    let entry = InstanceEntry {
+      ty: type_name::<T>(),
+      instance: OnceLock::new(),
+      source: InstanceSource::Factory(Box::new(move |context| Arc::new(Arc::new(factory(context))) as Instance)),
+    };
+
+    self.insert_instance::<T>(entry);
+  }
+
+  pub fn register_async_factory<T, E, F, Fut>(&self, factory: F)
+  where
+    T: Send + Sync + 'static,
+    E: Into<BoxError>,
+    F: Fn(&DIContext) -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = Result<T, E>> + Send + 'static,
+  {
+    let factory: AsyncInstanceFactory = Box::new(move |context| {
+      let future = factory(context);
+
+      Box::pin(async move {
+        match future.await {
+          Ok(instance) => Ok(Arc::new(Arc::new(instance)) as Instance),
+          Err(err) => Err(err.into()),
+        }
+      })
+    });
+
+    let entry = InstanceEntry {
+      ty: type_name::<T>(),
+      instance: OnceLock::new(),
+      source: InstanceSource::AsyncFactory(factory),
+    };
+
+    self.insert_instance::<T>(entry);
+  }
+
+  pub fn try_get_instance<T>(&self) -> Result<Arc<T>, DIError>
+  where
+    T: ?Sized + Send + Sync + 'static,
+  {
+    let entry = self.instances
+      .read()
+      .expect("Poisoned lock")
+      .get(&TypeId::of::<T>())
+      .cloned()
+      .ok_or(DIError::MissingInstance { ty: type_name::<T>() })?;
+
+    Ok(entry
+      .resolve(self)?
+      .downcast_ref::<Arc<T>>()
+      .cloned()
+      .expect("Failed to downcast instance"))
+  }
+
+  pub fn get_instance<T>(&self) -> Arc<T>
+  where
+    T: ?Sized + Send + Sync + 'static,
+  {
+    self.try_get_instance().unwrap_or_else(|err| panic!("{}", err))
+  }
+
+  pub(crate) fn has_instance(&self, type_id: TypeId) -> bool {
+    self.instances
+      .read()
+      .expect("Poisoned lock")
+      .contains_key(&type_id)
+  }
+
+  pub(crate) async fn initialize_instances(&self) -> Result<(), DIError> {
+    let entries = self.instances
+      .read()
+      .expect("Poisoned lock")
+      .values()
+      .filter(|entry| entry.instance.get().is_none())
+      .cloned()
+      .collect::<Vec<_>>();
+
+    for entry in entries {
+      let InstanceSource::AsyncFactory(factory) = &entry.source else {
+        continue;
+      };
+
+      log::debug!("Initializing {}", short_type_name(entry.ty));
+
+      let instance = factory(self).await.map_err(|err| DIError::Init {
+        service: short_type_name(entry.ty),
+        message: err.to_string(),
+      })?;
+
+      let _ = entry.instance.set(instance);
+    }
+
+    Ok(())
+  }
+
+  fn insert_instance<T>(&self, entry: InstanceEntry)
+  where
+    T: ?Sized + 'static,
+  {
+    self.instances
+      .write()
+      .expect("Poisoned lock")
+      .insert(TypeId::of::<T>(), Arc::new(entry));
+  }
+}
@@ -0,0 +1,85 @@
+use std::any::type_name;
+use std::ops::Deref;
+use std::sync::{Arc, OnceLock, Weak};
+use crate::di::{DIContext, DIError, Injectable};
+
+fn shared<T: ?Sized>(context: &DIContext) -> Result<Weak<DIContext>, DIError> {
+  context.this
+    .get()
+    .cloned()
+    .ok_or(DIError::NotShared { ty: type_name::<T>() })
+}
+
+fn upgrade<T: ?Sized>(context: &Weak<DIContext>) -> Arc<DIContext> {
+  context.upgrade().unwrap_or_else(|| {
+    panic!(
+      "Cannot resolve {}: DIContext was dropped",
+      type_name::<T>()
+    )
+  })
+}
+
+pub struct Lazy<T> {
+  context: Weak<DIContext>,
+  cell: OnceLock<Arc<T>>,
+}
+
+impl<T> Lazy<T>
+where
+  T: Injectable,
+{
+  pub fn try_new(context: &DIContext) -> Result<Self, DIError> {
+    Ok(Self {
+      context: shared::<T>(context)?,
+      cell: OnceLock::new(),
+    })
+  }
+
+  pub fn new(context: &DIContext) -> Self {
+    Self::try_new(context).unwrap_or_else(|err| panic!("{}", err))
+  }
+
+  pub fn get(&self) -> &Arc<T> {
+    self.cell.get_or_init(|| upgrade::<T>(&self.context).inject())
+  }
+}
+
+impl<T> Deref for Lazy<T>
+where
+  T: Injectable,
+{
+  type Target = T;
+
+  fn deref(&self) -> &Self::Target {
+    self.get()
+  }
+}
+
+pub struct Provider<T> {
+  context: Weak<DIContext>,
+  _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<T> Provider<T>
+where
+  T: Injectable,
+{
+  pub fn try_new(context: &DIContext) -> Result<Self, DIError> {
+    Ok(Self {
+      context: shared::<T>(context)?,
+      _marker: std::marker::PhantomData,
+    })
+  }
+
+  pub fn new(context: &DIContext) -> Self {
+    Self::try_new(context).unwrap_or_else(|err| panic!("{}", err))
+  }
+
+  pub fn try_get(&self) -> Result<Arc<T>, DIError> {
+    upgrade::<T>(&self.context).try_inject()
+  }
+
+  pub fn get(&self) -> Arc<T> {
+    upgrade::<T>(&self.context).inject()
+  }
+}
@@ -0,0 +1,114 @@
+use std::any::TypeId;
+use std::collections::HashSet;
+use std::future::Future;
+use std::pin::Pin;
+use crate::di::{ComponentInfo, DIContext, DIError, DependencyTarget, Scope};
+use crate::service::Service;
+
+pub type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+pub type InitFuture<'a> = Pin<Box<dyn Future<Output = Result<(), DIError>> + Send + 'a>>;
+
+pub(crate) fn init_service<S>(context: &DIContext) -> InitFuture<'_>
+where
+  S: Service,
+{
+  Box::pin(async move {
+    let init_error = |message: String| DIError::Init {
+      service: ComponentInfo::service::<S>().short_name(),
+      message,
+    };
+
+    let service = context.try_get_service::<S>().map_err(|err| init_error(err.to_string()))?;
+
+    service.init().await.map_err(|err| init_error(err.to_string()))
+  })
+}
+
+pub(crate) fn destroy_service<S>(context: &DIContext) -> InitFuture<'_>
+where
+  S: Service,
+{
+  Box::pin(async move {
+    let service = context.get_service::<S>();
+
+    service.pre_destroy().await.map_err(|err| DIError::Destroy {
+      service: ComponentInfo::service::<S>().short_name(),
+      message: err.to_string(),
+    })
+  })
+}
+
+pub(crate) fn init_nothing(_: &DIContext) -> InitFuture<'_> {
+  Box::pin(std::future::ready(Ok(())))
+}
+
+impl DIContext {
+  pub async fn initialize(&self, roots: &[ComponentInfo]) -> Result<(), DIError> {
+    let mut visited = HashSet::new();
+    let mut order = Vec::new();
+
+    for root in roots {
+      self.collect_init_order(*root, &mut visited, &mut order);
+    }
+
+    self.scheduled
+      .write()
+      .expect("Poisoned lock")
+      .extend(order.iter().map(|info| (info.type_id)()));
+
+    self.initialize_instances().await?;
+
+    for info in order {
+      log::debug!("Initializing {}", info.short_name());
+      (info.init)(self).await?;
+    }
+
+    Ok(())
+  }
+
+  pub async fn destroy(&self) -> Result<(), DIError> {
+    let created = std::mem::take(&mut *self.created.lock().expect("Poisoned lock"));
+    let mut result = Ok(());
+
+    for info in created.into_iter().rev() {
+      log::debug!("Destroying {}", info.short_name());
+
+      if let Err(err) = (info.destroy)(self).await {
+        log::error!("{}", err);
+        result = result.and(Err(err));
+      }
+    }
+
+    result
+  }
+
+  fn collect_init_order(&self, info: ComponentInfo, visited: &mut HashSet<TypeId>, order: &mut Vec<ComponentInfo>) {
+    if !visited.insert((info.type_id)()) || self.is_overridden((info.type_id)()) || self.is_disabled((info.type_id)()) {
+      return;
+    }
+
+    for dependency in (info.dependencies)() {
+      let targets = match dependency.target {
+        DependencyTarget::Component(target) => vec![target()],
+        DependencyTarget::Binding { type_id, .. } => self.binding_info(type_id()).map(|target| target()).into_iter().collect(),
+        DependencyTarget::Collection { type_id, .. } => self.collection_infos(type_id()).into_iter().map(|target| target()).collect(),
+        DependencyTarget::Injected { type_id, .. } => self.binding_info(type_id())
+          .map(|target| target())
+          .or_else(|| self.component_info(type_id()))
+          .into_iter()
+          .collect(),
+        DependencyTarget::Named { type_id, name, .. } => self.named_info(type_id(), name).map(|target| target()).into_iter().collect(),
+        DependencyTarget::Instance { .. } | DependencyTarget::Config { .. } => Vec::new(),
+      };
+
+      for target in targets {
+        self.collect_init_order(target, visited, order);
+      }
+    }
+
+    if info.scope == Scope::Singleton {
+      order.push(info);
+    }
+  }
+}
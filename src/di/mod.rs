@@ -1,72 +1,480 @@
-use std::any::{Any, TypeId};
-use std::collections::HashMap;
-use std::sync::{Arc, Mutex, OnceLock, RwLock};
+use std::any::{type_name, Any, TypeId};
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError, RwLock, Weak};
 use actix_web::web::Data;
 use sea_orm::DatabaseConnection;
+use serde::de::DeserializeOwned;
+use crate::discovery::DataSourceComponent;
 use crate::service::Service;
 
+pub use error::DIError;
+pub use export::{DependencyGraph, GraphEdge, GraphNode};
+pub use graph::{ComponentInfo, Dependency, DependencyTarget};
+pub use inject::Inject;
+pub use lazy::{Lazy, Provider};
+pub use lifecycle::{BoxError, InitFuture};
+pub use scope::{RequestScope, Scope, Scoped};
+
+use collection::CollectionEntry;
+use instance::InstanceEntry;
+use named::NamedEntry;
+use resolution::ResolutionGuard;
+
+mod collection;
+pub mod error;
+pub mod export;
+pub mod graph;
+pub mod inject;
+mod instance;
+pub mod lazy;
+pub mod lifecycle;
+mod named;
+mod overrides;
+pub(crate) mod resolution;
+pub mod scope;
+
 pub trait GetOrCreate {
   fn get_or_create(ctx: &DIContext) -> Data<Self>;
 }
 
+pub trait Injectable: Send + Sync + 'static {
+  fn try_resolve(context: &DIContext) -> Result<Arc<Self>, DIError>;
+
+  fn resolve(context: &DIContext) -> Arc<Self> {
+    Self::try_resolve(context).unwrap_or_else(|err| panic!("{}", err))
+  }
+
+  fn component_info() -> ComponentInfo;
+}
+
+pub trait Implements<I: ?Sized>: Send + Sync + 'static {
+  fn upcast(self: Arc<Self>) -> Arc<I>;
+}
+
+struct Binding<I: ?Sized> {
+  resolve: fn(&DIContext) -> Result<Arc<I>, DIError>,
+}
+
+struct BindingEntry {
+  info: fn() -> ComponentInfo,
+  binding: Arc<dyn Any + Send + Sync>,
+  primary: bool,
+  candidates: Vec<BindingCandidate>,
+}
+
+#[derive(Clone, Copy)]
+pub(crate) struct BindingCandidate {
+  pub name: &'static str,
+  pub info: fn() -> ComponentInfo,
+  pub primary: bool,
+}
+
+fn resolve_binding<I, T>(context: &DIContext) -> Result<Arc<I>, DIError>
+where
+  I: ?Sized + 'static,
+  T: Implements<I> + Service,
+{
+  Ok(context.try_get_service::<T>()?.upcast())
+}
+
+fn resolve_repository_binding<I, R>(context: &DIContext) -> Result<Arc<I>, DIError>
+where
+  I: ?Sized + 'static,
+  R: Implements<I> + From<DatabaseConnection>,
+{
+  Ok(context.try_get_repository::<R>()?.upcast())
+}
+
+type ServiceCell = Arc<Mutex<Option<Arc<dyn Any + Send + Sync>>>>;
+
 pub struct DIContext {
   repositories: RwLock<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>,
-  services: Mutex<HashMap<TypeId, Arc<OnceLock<Arc<dyn Any + Send + Sync>>>>>,
+  services: Mutex<HashMap<TypeId, ServiceCell>>,
+  bindings: RwLock<HashMap<TypeId, BindingEntry>>,
+  collections: RwLock<HashMap<TypeId, Vec<CollectionEntry>>>,
+  named: RwLock<HashMap<(TypeId, String), Arc<NamedEntry>>>,
+  instances: RwLock<HashMap<TypeId, Arc<InstanceEntry>>>,
+  components: RwLock<HashMap<TypeId, ComponentInfo>>,
+  disabled: RwLock<HashSet<TypeId>>,
+  primary: RwLock<HashMap<TypeId, String>>,
+  datasources: RwLock<HashMap<String, DatabaseConnection>>,
+  repository_datasources: RwLock<HashMap<TypeId, String>>,
+  overrides: RwLock<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>,
+  created: Mutex<Vec<ComponentInfo>>,
+  scheduled: RwLock<HashSet<TypeId>>,
+  config: serde_yaml::Value,
+  this: OnceLock<Weak<DIContext>>,
   db: DatabaseConnection,
 }
 
 impl DIContext {
   pub fn new(db: DatabaseConnection) -> Self {
-    Self {
+    let context = Self {
       repositories: RwLock::new(HashMap::new()),
       services: Mutex::new(HashMap::new()),
+      bindings: RwLock::new(HashMap::new()),
+      collections: RwLock::new(HashMap::new()),
+      named: RwLock::new(HashMap::new()),
+      instances: RwLock::new(HashMap::new()),
+      components: RwLock::new(HashMap::new()),
+      disabled: RwLock::new(HashSet::new()),
+      primary: RwLock::new(HashMap::new()),
+      datasources: RwLock::new(HashMap::new()),
+      repository_datasources: RwLock::new(HashMap::new()),
+      overrides: RwLock::new(HashMap::new()),
+      created: Mutex::new(Vec::new()),
+      scheduled: RwLock::new(HashSet::new()),
+      config: serde_yaml::Value::Null,
+      this: OnceLock::new(),
       db,
+    };
+
+    for datasource in inventory::iter::<DataSourceComponent> {
+      context.use_datasource_for((datasource.repository)(), datasource.datasource);
     }
+
+    context
   }
 
-  pub fn get_repository<T>(&self) -> Arc<T>
+  pub fn with_config(mut self, config: serde_yaml::Value) -> Self {
+    self.config = config;
+    self
+  }
+
+  pub fn into_shared(self) -> Arc<Self> {
+    Arc::new_cyclic(|this| {
+      let _ = self.this.set(this.clone());
+      self
+    })
+  }
+
+  pub fn weak(&self) -> Weak<Self> {
+    self.this.get().cloned().unwrap_or_default()
+  }
+
+  pub fn try_lazy<T>(&self) -> Result<Lazy<T>, DIError>
+  where
+    T: Injectable,
+  {
+    Lazy::try_new(self)
+  }
+
+  pub fn lazy<T>(&self) -> Lazy<T>
+  where
+    T: Injectable,
+  {
+    Lazy::new(self)
+  }
+
+  pub fn try_provider<T>(&self) -> Result<Provider<T>, DIError>
+  where
+    T: Injectable,
+  {
+    Provider::try_new(self)
+  }
+
+  pub fn provider<T>(&self) -> Provider<T>
+  where
+    T: Injectable,
+  {
+    Provider::new(self)
+  }
+
+  pub fn try_inject<T>(&self) -> Result<Arc<T>, DIError>
+  where
+    T: Injectable,
+  {
+    T::try_resolve(self)
+  }
+
+  pub fn inject<T>(&self) -> Arc<T>
+  where
+    T: Injectable,
+  {
+    T::resolve(self)
+  }
+
+  pub fn try_get_config<T>(&self, path: &str) -> Result<T, DIError>
+  where
+    T: DeserializeOwned,
+  {
+    let value = path
+      .split('.')
+      .try_fold(&self.config, |value, key| value.get(key))
+      .cloned()
+      .unwrap_or(serde_yaml::Value::Null);
+
+    serde_yaml::from_value(value).map_err(|err| DIError::Config {
+      path: path.to_string(),
+      message: err.to_string(),
+    })
+  }
+
+  pub fn get_config<T>(&self, path: &str) -> T
+  where
+    T: DeserializeOwned,
+  {
+    self.try_get_config(path).unwrap_or_else(|err| panic!("{}", err))
+  }
+
+  pub fn try_get_repository<T>(&self) -> Result<Arc<T>, DIError>
   where
     T: From<DatabaseConnection> + Send + Sync + 'static,
   {
+    if let Some(repository) = self.overridden::<T>() {
+      return Ok(repository);
+    }
+
+    if let Some(repository) = self.primary_named::<T>() {
+      return repository;
+    }
+
     {
       let repositories_guard = self.repositories.read().expect("Poisoned lock");
 
       if let Some(repository) = repositories_guard.get(&TypeId::of::<T>()) {
-        return repository
+        return Ok(repository
           .clone()
           .downcast()
-          .expect("Failed to downcast repository");
+          .expect("Failed to downcast repository"));
       }
     }
 
     let mut repositories_write_guard = self.repositories.write().expect("Poisoned lock");
-    let repository = Arc::new(T::from(self.db.clone()));
+    let repository = Arc::new(T::from(self.repository_db(TypeId::of::<T>())?));
 
     repositories_write_guard.insert(TypeId::of::<T>(), repository.clone());
 
-    repository
+    Ok(repository)
   }
 
-  pub fn get_service<T>(&self) -> Arc<T>
+  pub fn get_repository<T>(&self) -> Arc<T>
+  where
+    T: From<DatabaseConnection> + Send + Sync + 'static,
+  {
+    self.try_get_repository::<T>().unwrap_or_else(|err| panic!("{}", err))
+  }
+
+  pub fn try_get_service<T>(&self) -> Result<Arc<T>, DIError>
   where
     T: Service + Send + Sync + 'static,
   {
+    if let Some(service) = self.overridden::<T>() {
+      return Ok(service);
+    }
+
+    if let Some(service) = self.primary_named::<T>() {
+      return service;
+    }
+
+    if self.is_disabled(TypeId::of::<T>()) {
+      return Err(DIError::DisabledComponent { ty: type_name::<T>() });
+    }
+
+    let _guard = ResolutionGuard::enter::<T>()?;
+
+    match T::SCOPE {
+      Scope::Singleton => {}
+      Scope::Prototype => return self.construct(),
+      Scope::Request => {
+        let scope = RequestScope::current().ok_or(DIError::OutOfRequestScope { service: type_name::<T>() })?;
+
+        return scope.get_or_create(|| self.construct());
+      }
+    }
+
     let cell = {
       let mut services_guard = self.services.lock().expect("Poisoned lock");
 
       services_guard
         .entry(TypeId::of::<T>())
-        .or_insert_with(|| Arc::new(OnceLock::new()))
+        .or_default()
         .clone()
     };
 
-    let service = cell.get_or_init(|| {
-      Arc::new(T::new_service(self)) as Arc<dyn Any + Send + Sync>
-    });
+    let mut cell_guard = cell.lock().unwrap_or_else(PoisonError::into_inner);
+
+    let service = match &*cell_guard {
+      Some(service) => service.clone(),
+      None => {
+        if T::HAS_INIT && !self.scheduled.read().expect("Poisoned lock").contains(&TypeId::of::<T>()) {
+          return Err(DIError::NotInitialized { service: resolution::short_type_name(type_name::<T>()) });
+        }
+
+        let service = self.construct::<T>()? as Arc<dyn Any + Send + Sync>;
+        self.created.lock().expect("Poisoned lock").push(ComponentInfo::service::<T>());
+        cell_guard.insert(service).clone()
+      }
+    };
 
-    service
-      .clone()
+    Ok(service
       .downcast::<T>()
-      .expect("Failed to downcast service")
+      .expect("Failed to downcast service"))
+  }
+
+  pub fn get_service<T>(&self) -> Arc<T>
+  where
+    T: Service + Send + Sync + 'static,
+  {
+    self.try_get_service::<T>().unwrap_or_else(|err| panic!("{}", err))
+  }
+
+  fn construct<T>(&self) -> Result<Arc<T>, DIError>
+  where
+    T: Service,
+  {
+    let service = Arc::new(T::try_new_service(self)?);
+    service.post_construct();
+    Ok(service)
+  }
+
+  pub fn bind<I, T>(&self)
+  where
+    I: ?Sized + 'static,
+    T: Implements<I> + Service,
+  {
+    self.insert_binding::<I>(type_name::<T>(), ComponentInfo::service::<T>, resolve_binding::<I, T>, false);
+  }
+
+  pub fn bind_primary<I, T>(&self)
+  where
+    I: ?Sized + 'static,
+    T: Implements<I> + Service,
+  {
+    self.insert_binding::<I>(type_name::<T>(), ComponentInfo::service::<T>, resolve_binding::<I, T>, true);
+  }
+
+  pub fn bind_repository<I, R>(&self)
+  where
+    I: ?Sized + 'static,
+    R: Implements<I> + From<DatabaseConnection>,
+  {
+    self.insert_binding::<I>(type_name::<R>(), ComponentInfo::repository::<R>, resolve_repository_binding::<I, R>, false);
+  }
+
+  fn insert_binding<I>(
+    &self,
+    implementation: &'static str,
+    info: fn() -> ComponentInfo,
+    resolve: fn(&DIContext) -> Result<Arc<I>, DIError>,
+    primary: bool,
+  ) where
+    I: ?Sized + 'static,
+  {
+    let binding: Arc<dyn Any + Send + Sync> = Arc::new(Binding::<I> { resolve });
+
+    let mut bindings_guard = self.bindings.write().expect("Poisoned lock");
+
+    let entry = bindings_guard.entry(TypeId::of::<I>()).or_insert_with(|| BindingEntry {
+      info,
+      binding: binding.clone(),
+      primary,
+      candidates: Vec::new(),
+    });
+
+    entry.candidates.retain(|candidate| candidate.name != implementation);
+    entry.candidates.push(BindingCandidate {
+      name: implementation,
+      info,
+      primary,
+    });
+
+    if primary || !entry.primary {
+      entry.info = info;
+      entry.binding = binding;
+      entry.primary = primary;
+    }
+  }
+
+  pub fn try_get_binding<I>(&self) -> Result<Arc<I>, DIError>
+  where
+    I: ?Sized + Send + Sync + 'static,
+  {
+    if let Some(instance) = self.overridden::<I>() {
+      return Ok(instance);
+    }
+
+    if let Some(instance) = self.primary_named::<I>() {
+      return instance;
+    }
+
+    self.check_unambiguous(TypeId::of::<I>(), type_name::<I>())?;
+
+    let resolve = {
+      let bindings_guard = self.bindings.read().expect("Poisoned lock");
+
+      bindings_guard
+        .get(&TypeId::of::<I>())
+        .and_then(|entry| entry.binding.downcast_ref::<Binding<I>>())
+        .map(|binding| binding.resolve)
+        .ok_or(DIError::MissingBinding {
+          interface: type_name::<I>(),
+        })?
+    };
+
+    resolve(self)
+  }
+
+  pub fn get_binding<I>(&self) -> Arc<I>
+  where
+    I: ?Sized + Send + Sync + 'static,
+  {
+    self.try_get_binding::<I>().unwrap_or_else(|err| panic!("{}", err))
+  }
+
+  pub(crate) fn binding_info(&self, interface: TypeId) -> Option<fn() -> ComponentInfo> {
+    self.bindings
+      .read()
+      .expect("Poisoned lock")
+      .get(&interface)
+      .map(|entry| entry.info)
+  }
+
+  pub(crate) fn binding_candidates(&self, interface: TypeId) -> Vec<(BindingCandidate, bool)> {
+    let named_primary = self.has_primary(interface);
+    let bindings_guard = self.bindings.read().expect("Poisoned lock");
+
+    let Some(entry) = bindings_guard.get(&interface) else {
+      return Vec::new();
+    };
+
+    let primaries = entry.candidates.iter().filter(|candidate| candidate.primary).count();
+    let ambiguous = !named_primary && primaries != 1 && entry.candidates.len() > 1;
+
+    entry.candidates.iter()
+      .map(|candidate| (*candidate, ambiguous && (primaries == 0 || candidate.primary)))
+      .collect()
+  }
+
+  pub(crate) fn check_unambiguous(&self, interface: TypeId, name: &'static str) -> Result<(), DIError> {
+    let candidates = self.binding_candidates(interface)
+      .into_iter()
+      .filter(|(_, ambiguous)| *ambiguous)
+      .map(|(candidate, _)| resolution::short_type_name(candidate.name))
+      .collect::<Vec<_>>();
+
+    if candidates.is_empty() {
+      return Ok(());
+    }
+
+    Err(DIError::AmbiguousBinding {
+      interface: name,
+      candidates,
+    })
+  }
+
+
+  fn use_datasource_for(&self, repository: TypeId, datasource: &str) {
+    self.repository_datasources
+      .write()
+      .expect("Poisoned lock")
+      .insert(repository, datasource.to_string());
+  }
+
+  pub(crate) fn has_config(&self, path: &str) -> bool {
+    path
+      .split('.')
+      .try_fold(&self.config, |value, key| value.get(key))
+      .is_some_and(|value| !value.is_null())
   }
 }
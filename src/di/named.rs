@@ -0,0 +1,242 @@
+use std::any::{type_name, Any, TypeId};
+use std::sync::{Arc, OnceLock};
+use sea_orm::DatabaseConnection;
+use crate::di::{ComponentInfo, DIContext, DIError, Implements};
+use crate::service::Service;
+
+type Instance = Arc<dyn Any + Send + Sync>;
+
+type NamedFactory = Box<dyn Fn(&DIContext) -> Instance + Send + Sync>;
+
+enum NamedSource {
+  Provided,
+  Factory(NamedFactory),
+  Service(fn(&DIContext) -> Result<Instance, DIError>),
+}
+
+pub(crate) struct NamedEntry {
+  instance: OnceLock<Instance>,
+  source: NamedSource,
+  info: Option<fn() -> ComponentInfo>,
+}
+
+impl NamedEntry {
+  fn resolve(&self, context: &DIContext) -> Result<Instance, DIError> {
+    match &self.source {
+      NamedSource::Provided => Ok(self.instance.get().cloned().expect("Provided named instance must be initialized")),
+      NamedSource::Factory(factory) => Ok(self.instance.get_or_init(|| factory(context)).clone()),
+      NamedSource::Service(resolve) => resolve(context),
+    }
+  }
+}
+
+fn resolve_named_binding<I, T>(context: &DIContext) -> Result<Instance, DIError>
+where
+  I: ?Sized + Send + Sync + 'static,
+  T: Implements<I> + Service,
+{
+  Ok(Arc::new(context.try_get_service::<T>()?.upcast()))
+}
+
+fn resolve_named_service<T>(context: &DIContext) -> Result<Instance, DIError>
+where
+  T: Service,
+{
+  Ok(Arc::new(context.try_get_service::<T>()?))
+}
+
+impl DIContext {
+  pub fn provide_named<T>(&self, name: impl Into<String>, instance: Arc<T>)
+  where
+    T: ?Sized + Send + Sync + 'static,
+  {
+    let entry = NamedEntry {
+      instance: OnceLock::from(Arc::new(instance) as Instance),
+      source: NamedSource::Provided,
+      info: None,
+    };
+
+    self.insert_named::<T>(name.into(), entry);
+  }
+
+  pub fn register_named<T, F>(&self, name: impl Into<String>, factory: F)
+  where
+    T: Send + Sync + 'static,
+    F: Fn(&DIContext) -> T + Send + Sync + 'static,
+  {
+    self.register_named_shared::<T, _>(name, move |context| Arc::new(factory(context)));
+  }
+
+  pub fn register_named_shared<T, F>(&self, name: impl Into<String>, factory: F)
+  where
+    T: ?Sized + Send + Sync + 'static,
+    F: Fn(&DIContext) -> Arc<T> + Send + Sync + 'static,
+  {
+    let entry = NamedEntry {
+      instance: OnceLock::new(),
+      source: NamedSource::Factory(Box::new(move |context| Arc::new(factory(context)) as Instance)),
+      info: None,
+    };
+
+    self.insert_named::<T>(name.into(), entry);
+  }
+
+  pub fn register_named_repository<R>(&self, name: impl Into<String>, datasource: impl Into<String>)
+  where
+    R: From<DatabaseConnection> + Send + Sync + 'static,
+  {
+    let datasource = datasource.into();
+    self.register_named::<R, _>(name, move |context| R::from(context.datasource(&datasource)));
+  }
+
+  pub fn bind_named<I, T>(&self, name: impl Into<String>)
+  where
+    I: ?Sized + Send + Sync + 'static,
+    T: Implements<I> + Service,
+  {
+    self.insert_named_service::<I>(name.into(), resolve_named_binding::<I, T>, ComponentInfo::service::<T>);
+  }
+
+  pub fn bind_named_service<T>(&self, name: impl Into<String>)
+  where
+    T: Service,
+  {
+    self.insert_named_service::<T>(name.into(), resolve_named_service::<T>, ComponentInfo::service::<T>);
+  }
+
+  pub fn set_primary<T>(&self, name: impl Into<String>)
+  where
+    T: ?Sized + 'static,
+  {
+    self.primary
+      .write()
+      .expect("Poisoned lock")
+      .insert(TypeId::of::<T>(), name.into());
+  }
+
+  pub fn try_get_named<T>(&self, name: &str) -> Result<Arc<T>, DIError>
+  where
+    T: ?Sized + Send + Sync + 'static,
+  {
+    let entry = self.named
+      .read()
+      .expect("Poisoned lock")
+      .get(&(TypeId::of::<T>(), name.to_string()))
+      .cloned()
+      .ok_or_else(|| DIError::MissingNamed {
+        name: name.to_string(),
+        ty: type_name::<T>(),
+      })?;
+
+    Ok(entry
+      .resolve(self)?
+      .downcast_ref::<Arc<T>>()
+      .cloned()
+      .expect("Failed to downcast named component"))
+  }
+
+  pub fn get_named<T>(&self, name: &str) -> Arc<T>
+  where
+    T: ?Sized + Send + Sync + 'static,
+  {
+    self.try_get_named(name).unwrap_or_else(|err| panic!("{}", err))
+  }
+
+  pub fn add_datasource(&self, name: impl Into<String>, db: DatabaseConnection) {
+    self.datasources
+      .write()
+      .expect("Poisoned lock")
+      .insert(name.into(), db);
+  }
+
+  pub fn use_datasource<R>(&self, datasource: impl Into<String>)
+  where
+    R: 'static,
+  {
+    self.use_datasource_for(TypeId::of::<R>(), &datasource.into());
+  }
+
+  pub fn try_datasource(&self, name: &str) -> Result<DatabaseConnection, DIError> {
+    self.datasources
+      .read()
+      .expect("Poisoned lock")
+      .get(name)
+      .cloned()
+      .ok_or_else(|| DIError::MissingDatasource { name: name.to_string() })
+  }
+
+  pub fn datasource(&self, name: &str) -> DatabaseConnection {
+    self.try_datasource(name).unwrap_or_else(|err| panic!("{}", err))
+  }
+
+  pub(crate) fn primary_named<T>(&self) -> Option<Result<Arc<T>, DIError>>
+  where
+    T: ?Sized + Send + Sync + 'static,
+  {
+    let name = self.primary
+      .read()
+      .expect("Poisoned lock")
+      .get(&TypeId::of::<T>())
+      .cloned()?;
+
+    Some(self.try_get_named(&name))
+  }
+
+  pub(crate) fn has_named(&self, type_id: TypeId, name: &str) -> bool {
+    self.named
+      .read()
+      .expect("Poisoned lock")
+      .contains_key(&(type_id, name.to_string()))
+  }
+
+  pub(crate) fn named_info(&self, type_id: TypeId, name: &str) -> Option<fn() -> ComponentInfo> {
+    self.named
+      .read()
+      .expect("Poisoned lock")
+      .get(&(type_id, name.to_string()))
+      .and_then(|entry| entry.info)
+  }
+
+  pub(crate) fn has_primary(&self, type_id: TypeId) -> bool {
+    self.primary
+      .read()
+      .expect("Poisoned lock")
+      .contains_key(&type_id)
+  }
+
+  pub(crate) fn repository_db(&self, type_id: TypeId) -> Result<DatabaseConnection, DIError> {
+    let datasource = self.repository_datasources
+      .read()
+      .expect("Poisoned lock")
+      .get(&type_id)
+      .cloned();
+
+    match datasource {
+      Some(datasource) => self.try_datasource(&datasource),
+      None => Ok(self.db.clone()),
+    }
+  }
+
+  fn insert_named_service<T>(&self, name: String, resolve: fn(&DIContext) -> Result<Instance, DIError>, info: fn() -> ComponentInfo)
+  where
+    T: ?Sized + 'static,
+  {
+    let entry = NamedEntry {
+      instance: OnceLock::new(),
+      source: NamedSource::Service(resolve),
+      info: Some(info),
+    };
+
+    self.insert_named::<T>(name, entry);
+  }
+
+  fn insert_named<T>(&self, name: String, entry: NamedEntry)
+  where
+    T: ?Sized + 'static,
+  {
+    self.named
+      .write()
+      .expect("Poisoned lock")
+      .insert((TypeId::of::<T>(), name), Arc::new(entry));
+  }
+}
@@ -0,0 +1,57 @@
+use std::any::TypeId;
+use std::sync::Arc;
+use sea_orm::DatabaseConnection;
+use crate::di::DIContext;
+use crate::service::Service;
+
+impl DIContext {
+  pub fn override_repository<R>(&self, repository: R)
+  where
+    R: From<DatabaseConnection> + Send + Sync + 'static,
+  {
+    self.override_instance(Arc::new(repository));
+  }
+
+  pub fn override_service<S>(&self, service: S)
+  where
+    S: Service,
+  {
+    self.override_instance(Arc::new(service));
+  }
+
+  pub fn override_binding<I>(&self, instance: Arc<I>)
+  where
+    I: ?Sized + Send + Sync + 'static,
+  {
+    self.override_instance(instance);
+  }
+
+  fn override_instance<T>(&self, instance: Arc<T>)
+  where
+    T: ?Sized + Send + Sync + 'static,
+  {
+    self.overrides
+      .write()
+      .expect("Poisoned lock")
+      .insert(TypeId::of::<T>(), Arc::new(instance));
+  }
+
+  pub(crate) fn overridden<T>(&self) -> Option<Arc<T>>
+  where
+    T: ?Sized + Send + Sync + 'static,
+  {
+    self.overrides
+      .read()
+      .expect("Poisoned lock")
+      .get(&TypeId::of::<T>())
+      .and_then(|instance| instance.downcast_ref::<Arc<T>>())
+      .cloned()
+  }
+
+  pub(crate) fn is_overridden(&self, type_id: TypeId) -> bool {
+    self.overrides
+      .read()
+      .expect("Poisoned lock")
+      .contains_key(&type_id)
+  }
+}
@@ -0,0 +1,46 @@
+use std::any::{type_name, TypeId};
+use std::cell::RefCell;
+use std::sync::LazyLock;
+use regex::Regex;
+use crate::di::DIError;
+
+static MODULE_PATH: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\b[a-z_][a-z0-9_]*::").unwrap());
+
+thread_local! {
+  static RESOLUTION_STACK: RefCell<Vec<(TypeId, &'static str)>> = const { RefCell::new(Vec::new()) };
+}
+
+pub(crate) struct ResolutionGuard;
+
+impl ResolutionGuard {
+  pub(crate) fn enter<T>() -> Result<Self, DIError>
+  where
+    T: ?Sized + 'static,
+  {
+    RESOLUTION_STACK.with(|stack| {
+      let mut stack = stack.borrow_mut();
+
+      if let Some(index) = stack.iter().position(|(type_id, _)| *type_id == TypeId::of::<T>()) {
+        let path = stack[index..].iter()
+          .map(|(_, name)| short_type_name(name))
+          .chain(std::iter::once(short_type_name(type_name::<T>())))
+          .collect();
+
+        return Err(DIError::Cycle { path });
+      }
+
+      stack.push((TypeId::of::<T>(), type_name::<T>()));
+      Ok(ResolutionGuard)
+    })
+  }
+}
+
+impl Drop for ResolutionGuard {
+  fn drop(&mut self) {
+    RESOLUTION_STACK.with(|stack| stack.borrow_mut().pop());
+  }
+}
+
+pub(crate) fn short_type_name(name: &str) -> String {
+  MODULE_PATH.replace_all(name, "").to_string()
+}
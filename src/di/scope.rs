@@ -0,0 +1,128 @@
+use std::any::{type_name, Any, TypeId};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::future::{ready, Ready};
+use std::ops::Deref;
+use std::sync::{Arc, Mutex};
+use actix_web::dev::Payload;
+use actix_web::error::ErrorInternalServerError;
+use actix_web::web::Data;
+use actix_web::{FromRequest, HttpMessage, HttpRequest};
+use crate::di::{DIContext, DIError};
+use crate::service::Service;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scope {
+  Singleton,
+  Prototype,
+  Request,
+}
+
+thread_local! {
+  static CURRENT_REQUEST_SCOPE: RefCell<Option<Arc<RequestScope>>> = const { RefCell::new(None) };
+}
+
+#[derive(Default)]
+pub struct RequestScope {
+  instances: Mutex<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>,
+}
+
+pub struct RequestScopeGuard {
+  previous: Option<Arc<RequestScope>>,
+}
+
+impl RequestScope {
+  pub fn enter(self: &Arc<Self>) -> RequestScopeGuard {
+    let previous = CURRENT_REQUEST_SCOPE.with(|current| current.replace(Some(self.clone())));
+    RequestScopeGuard { previous }
+  }
+
+  pub fn current() -> Option<Arc<RequestScope>> {
+    CURRENT_REQUEST_SCOPE.with(|current| current.borrow().clone())
+  }
+
+  pub(crate) fn get_or_create<T, F>(&self, create: F) -> Result<Arc<T>, DIError>
+  where
+    T: Send + Sync + 'static,
+    F: FnOnce() -> Result<Arc<T>, DIError>,
+  {
+    let existing = self.instances
+      .lock()
+      .expect("Poisoned lock")
+      .get(&TypeId::of::<T>())
+      .cloned();
+
+    let instance = match existing {
+      Some(instance) => instance,
+      None => {
+        let created = create()? as Arc<dyn Any + Send + Sync>;
+
+        self.instances
+          .lock()
+          .expect("Poisoned lock")
+          .entry(TypeId::of::<T>())
+          .or_insert(created)
+          .clone()
+      }
+    };
+
+    Ok(instance
+      .downcast::<T>()
+      .expect("Failed to downcast request scoped service"))
+  }
+
+  pub(crate) fn from_request(req: &HttpRequest) -> Arc<RequestScope> {
+    if let Some(scope) = req.extensions().get::<Arc<RequestScope>>() {
+      return scope.clone();
+    }
+
+    let scope = Arc::new(RequestScope::default());
+    req.extensions_mut().insert(scope.clone());
+    scope
+  }
+}
+
+impl Drop for RequestScopeGuard {
+  fn drop(&mut self) {
+    let previous = self.previous.take();
+    CURRENT_REQUEST_SCOPE.with(|current| current.replace(previous));
+  }
+}
+
+pub struct Scoped<T>(pub Arc<T>);
+
+impl<T> Scoped<T> {
+  pub fn into_inner(self) -> Arc<T> {
+    self.0
+  }
+}
+
+impl<T> Deref for Scoped<T> {
+  type Target = T;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<T> FromRequest for Scoped<T>
+where
+  T: Service,
+{
+  type Error = actix_web::Error;
+  type Future = Ready<Result<Self, Self::Error>>;
+
+  fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+    let Some(context) = req.app_data::<Data<DIContext>>() else {
+      return ready(Err(ErrorInternalServerError(format!(
+        "DIContext is not registered as app data, cannot resolve {}",
+        type_name::<T>()
+      ))));
+    };
+
+    let scope = RequestScope::from_request(req);
+    let _guard = scope.enter();
+
+    ready(context.try_get_service::<T>().map(Scoped).map_err(ErrorInternalServerError))
+  }
+}
@@ -0,0 +1,81 @@
+use std::any::TypeId;
+use crate::di::DIContext;
+
+const ACTIVE_PROFILES: &str = "profiles.active";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Condition {
+  OnProperty {
+    path: &'static str,
+    having: Option<&'static str>,
+  },
+  OnMissingBean,
+  OnFeature {
+    feature: &'static str,
+    enabled: bool,
+  },
+}
+
+impl Condition {
+  pub fn matches(&self, context: &DIContext, type_id: TypeId) -> bool {
+    match self {
+      Condition::OnProperty { path, having } => {
+        let value = context.try_get_config::<Option<serde_yaml::Value>>(path).ok().flatten();
+
+        match (value, having) {
+          (None, _) => false,
+          (Some(value), Some(having)) => property_string(&value).is_some_and(|value| value == *having),
+          (Some(value), None) => !matches!(value, serde_yaml::Value::Null | serde_yaml::Value::Bool(false)),
+        }
+      }
+      Condition::OnMissingBean => !context.has_bean(type_id),
+      Condition::OnFeature { enabled, .. } => *enabled,
+    }
+  }
+}
+
+fn property_string(value: &serde_yaml::Value) -> Option<String> {
+  match value {
+    serde_yaml::Value::String(value) => Some(value.clone()),
+    serde_yaml::Value::Bool(value) => Some(value.to_string()),
+    serde_yaml::Value::Number(value) => Some(value.to_string()),
+    _ => None,
+  }
+}
+
+impl DIContext {
+  pub fn active_profiles(&self) -> Vec<String> {
+    if let Ok(profiles) = self.try_get_config::<Vec<String>>(ACTIVE_PROFILES) {
+      return profiles;
+    }
+
+    self.try_get_config::<Option<String>>(ACTIVE_PROFILES)
+      .ok()
+      .flatten()
+      .map(|profiles| {
+        profiles
+          .split(',')
+          .map(str::trim)
+          .filter(|profile| !profile.is_empty())
+          .map(str::to_string)
+          .collect()
+      })
+      .unwrap_or_default()
+  }
+
+  pub fn is_active(&self, profiles: &[&str], conditions: &[Condition], type_id: TypeId) -> bool {
+    let active_profiles = self.active_profiles();
+
+    let profile_matches = profiles.is_empty()
+      || profiles.iter().any(|profile| active_profiles.iter().any(|active| active == profile));
+
+    profile_matches && conditions.iter().all(|condition| condition.matches(self, type_id))
+  }
+
+  pub(crate) fn has_bean(&self, type_id: TypeId) -> bool {
+    self.binding_info(type_id).is_some()
+      || self.has_primary(type_id)
+      || self.has_instance(type_id)
+      || self.is_overridden(type_id)
+  }
+}
@@ -0,0 +1,183 @@
+use std::any::TypeId;
+use std::collections::HashMap;
+use sea_orm::DatabaseConnection;
+use crate::controller::Controller;
+use crate::di::{ComponentInfo, DIContext};
+use crate::repository::Repository;
+use crate::server::config::ApplicationServerConfigurer;
+use crate::server::config::registry::{RepositoryRegistrator, ServiceRegistrator};
+use crate::service::Service;
+
+pub use condition::Condition;
+pub use inventory;
+
+mod condition;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ComponentKind {
+  Service,
+  Repository,
+  Controller,
+}
+
+pub struct Component {
+  pub name: &'static str,
+  pub module_path: &'static str,
+  pub kind: ComponentKind,
+  pub profiles: &'static [&'static str],
+  pub conditions: &'static [Condition],
+  pub register: fn(&mut ApplicationServerConfigurer),
+  pub info: fn() -> ComponentInfo,
+}
+
+inventory::collect!(Component);
+
+pub struct BindingComponent {
+  pub interface: &'static str,
+  pub interface_id: fn() -> TypeId,
+  pub implementation: &'static str,
+  pub name: Option<&'static str>,
+  pub primary: bool,
+  pub profiles: &'static [&'static str],
+  pub conditions: &'static [Condition],
+  pub bind: fn(&DIContext),
+}
+
+inventory::collect!(BindingComponent);
+
+pub struct DataSourceComponent {
+  pub repository: fn() -> TypeId,
+  pub datasource: &'static str,
+}
+
+inventory::collect!(DataSourceComponent);
+
+impl Component {
+  pub fn is_in_module(&self, module: &str) -> bool {
+    self.module_path == module
+      || self.module_path
+        .strip_prefix(module)
+        .is_some_and(|rest| rest.starts_with("::"))
+  }
+
+  pub fn is_active_for(&self, profiles: &[String]) -> bool {
+    self.profiles.is_empty() || self.profiles.iter().any(|profile| profiles.iter().any(|active| active == profile))
+  }
+
+  pub fn is_enabled(&self, context: &DIContext) -> bool {
+    !context.is_disabled(((self.info)().type_id)())
+  }
+
+  fn is_active(&self, context: &DIContext, bound: &HashMap<&'static str, bool>) -> bool {
+    let conditions = self.conditions.iter()
+      .filter(|condition| **condition != Condition::OnMissingBean)
+      .copied()
+      .collect::<Vec<_>>();
+
+    context.is_active(self.profiles, &conditions, ((self.info)().type_id)())
+      && bound.get(self.name).copied().unwrap_or(true)
+  }
+}
+
+impl BindingComponent {
+  pub fn is_enabled(&self, context: &DIContext) -> bool {
+    context.is_active(self.profiles, self.conditions, (self.interface_id)())
+  }
+
+  fn is_fallback(&self) -> bool {
+    self.conditions.contains(&Condition::OnMissingBean)
+  }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ComponentFilter {
+  modules: Vec<String>,
+  profiles: Vec<String>,
+}
+
+impl ComponentFilter {
+  pub fn module(mut self, module: impl Into<String>) -> Self {
+    self.modules.push(module.into());
+    self
+  }
+
+  pub fn profile(mut self, profile: impl Into<String>) -> Self {
+    self.profiles.push(profile.into());
+    self
+  }
+
+  pub fn profiles(mut self, profiles: impl IntoIterator<Item = String>) -> Self {
+    self.profiles.extend(profiles);
+    self
+  }
+
+  pub fn matches(&self, component: &Component) -> bool {
+    let in_modules = self.modules.is_empty() || self.modules.iter().any(|module| component.is_in_module(module));
+    in_modules && component.is_active_for(&self.profiles)
+  }
+}
+
+pub fn components() -> impl Iterator<Item = &'static Component> {
+  inventory::iter::<Component>.into_iter()
+}
+
+pub fn discover(filter: &ComponentFilter) -> impl Iterator<Item = &'static Component> + '_ {
+  components().filter(|component| filter.matches(component))
+}
+
+fn apply_bindings(context: &DIContext) -> HashMap<&'static str, bool> {
+  let mut bindings = inventory::iter::<BindingComponent>.into_iter().collect::<Vec<_>>();
+  bindings.sort_by_key(|binding| (binding.is_fallback(), binding.primary));
+
+  let mut bound = HashMap::new();
+
+  for binding in bindings {
+    let enabled = binding.is_enabled(context);
+
+    if enabled {
+      (binding.bind)(context);
+    } else {
+      log::debug!("Skipping binding {} for {}", binding.implementation, binding.interface);
+    }
+
+    *bound.entry(binding.implementation).or_insert(false) |= enabled;
+  }
+
+  bound
+}
+
+impl DIContext {
+  pub fn register_discovered(&self) {
+    let bound = apply_bindings(self);
+
+    for component in components() {
+      if component.is_active(self, &bound) {
+        self.register_component((component.info)());
+      } else {
+        log::debug!("Disabling {:?} {}: conditions not met", component.kind, component.name);
+        self.disable_component(((component.info)().type_id)());
+      }
+    }
+  }
+}
+
+pub fn register_service<S>(configurer: &mut ApplicationServerConfigurer)
+where
+  S: Service,
+{
+  ServiceRegistrator::register::<S>(configurer);
+}
+
+pub fn register_repository<R>(configurer: &mut ApplicationServerConfigurer)
+where
+  R: From<DatabaseConnection> + Repository,
+{
+  RepositoryRegistrator::register::<R>(configurer);
+}
+
+pub fn register_controller<C>(configurer: &mut ApplicationServerConfigurer)
+where
+  C: Controller,
+{
+  C::configure(configurer.service_config, &configurer.context);
+}
@@ -2,6 +2,10 @@ pub mod config;
 pub mod server;
 pub mod repository;
 pub mod service;
+pub mod controller;
 pub mod di;
 pub mod registry;
+pub mod discovery;
+pub mod module;
+pub mod validation;
 pub mod prelude;
@@ -0,0 +1,121 @@
+use std::any::{type_name, TypeId};
+use std::collections::HashSet;
+use std::sync::Arc;
+use sea_orm::DatabaseConnection;
+use serde::de::DeserializeOwned;
+use crate::controller::Controller;
+use crate::di::resolution::short_type_name;
+use crate::di::{ComponentInfo, DIContext, Implements};
+use crate::discovery;
+use crate::repository::Repository;
+use crate::registry::Registry;
+use crate::server::{RegistryFn, SetupFn};
+use crate::service::Service;
+
+pub trait Module: 'static {
+  fn configure(&self, module: &mut ModuleBuilder);
+}
+
+#[derive(Default)]
+pub struct ModuleBuilder {
+  pub(crate) registries: Vec<RegistryFn>,
+  pub(crate) components: Vec<ComponentInfo>,
+  pub(crate) setups: Vec<SetupFn>,
+  pub(crate) registrations: Vec<String>,
+  included: HashSet<TypeId>,
+  current: Vec<String>,
+}
+
+impl ModuleBuilder {
+  pub fn module<M>(&mut self, module: M) -> &mut Self
+  where
+    M: Module,
+  {
+    if !self.included.insert(TypeId::of::<M>()) {
+      return self;
+    }
+
+    self.current.push(short_type_name(type_name::<M>()));
+    module.configure(self);
+    self.current.pop();
+    self
+  }
+
+  pub fn service<S>(&mut self) -> &mut Self
+  where
+    S: Service,
+  {
+    self.record("service", type_name::<S>());
+    self.registries.push(discovery::register_service::<S>);
+    self.components.push(ComponentInfo::service::<S>());
+    self
+  }
+
+  pub fn repository<R>(&mut self) -> &mut Self
+  where
+    R: From<DatabaseConnection> + Repository,
+  {
+    self.record("repository", type_name::<R>());
+    self.registries.push(discovery::register_repository::<R>);
+    self.components.push(ComponentInfo::repository::<R>());
+    self
+  }
+
+  pub fn controller<C>(&mut self) -> &mut Self
+  where
+    C: Controller,
+  {
+    self.record("controller", type_name::<C>());
+    self.registries.push(discovery::register_controller::<C>);
+    self.components.push(ComponentInfo::controller::<C>());
+    self
+  }
+
+  pub fn registry<R>(&mut self) -> &mut Self
+  where
+    R: Registry,
+  {
+    self.record("registry", type_name::<R>());
+    self.registries.push(R::register_all);
+    self.components.extend(R::components());
+    self
+  }
+
+  pub fn bind<I, T>(&mut self) -> &mut Self
+  where
+    I: ?Sized + 'static,
+    T: Implements<I> + Service,
+  {
+    self.record("binding", &format!("{} -> {}", type_name::<I>(), short_type_name(type_name::<T>())));
+    self.setup(|context| context.bind::<I, T>())
+  }
+
+  pub fn config<T>(&mut self, path: &'static str) -> &mut Self
+  where
+    T: DeserializeOwned + Send + Sync + 'static,
+  {
+    self.record("config", &format!("{} at '{}'", type_name::<T>(), path));
+    self.setup(move |context| context.register_config::<T>(path))
+  }
+
+  pub fn instance<T>(&mut self, instance: Arc<T>) -> &mut Self
+  where
+    T: ?Sized + Send + Sync + 'static,
+  {
+    self.record("instance", type_name::<T>());
+    self.setup(move |context| context.register_instance(instance))
+  }
+
+  pub fn setup<F>(&mut self, setup: F) -> &mut Self
+  where
+    F: FnOnce(&DIContext) + 'static,
+  {
+    self.setups.push(Box::new(setup));
+    self
+  }
+
+  pub(crate) fn record(&mut self, kind: &str, name: &str) {
+    let module = self.current.last().map(String::as_str).unwrap_or("application");
+    self.registrations.push(format!("[{}] {} {}", module, kind, short_type_name(name)));
+  }
+}
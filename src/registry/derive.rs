@@ -0,0 +1 @@
+pub use actix_boot_service_macros::Registry;
\ No newline at end of file
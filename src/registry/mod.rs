@@ -1,5 +1,12 @@
+use crate::di::ComponentInfo;
 use crate::server::config::ApplicationServerConfigurer;
 
+pub mod derive;
+
 pub trait Registry {
   fn register_all(context: &mut ApplicationServerConfigurer);
+
+  fn components() -> Vec<ComponentInfo> {
+    Vec::new()
+  }
 }
\ No newline at end of file
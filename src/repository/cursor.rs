@@ -0,0 +1,288 @@
+use std::str::FromStr;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use sea_orm::prelude::{
+  ChronoDate, ChronoDateTime, ChronoDateTimeWithTimeZone, ChronoTime, Decimal, Uuid,
+};
+use sea_orm::{DbErr, Value};
+use crate::repository::QueryError;
+use serde::{Deserialize, Serialize};
+
+const DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
+
+pub const MAX_CURSOR_SIZE: u64 = 100;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CursorPage<T> {
+  pub items: Vec<T>,
+  pub size: u64,
+  pub next_cursor: Option<String>,
+  pub prev_cursor: Option<String>,
+}
+
+impl<T> CursorPage<T> {
+  pub fn has_next(&self) -> bool {
+    self.next_cursor.is_some()
+  }
+
+  pub fn has_prev(&self) -> bool {
+    self.prev_cursor.is_some()
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub(crate) enum CursorDirection {
+  #[serde(rename = "n")]
+  Next,
+  #[serde(rename = "p")]
+  Prev,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct Cursor {
+  pub direction: CursorDirection,
+  pub keys: Vec<Option<Value>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Token {
+  d: CursorDirection,
+  k: Vec<Option<(String, String)>>,
+}
+
+impl Cursor {
+  pub fn encode(&self) -> Result<String, QueryError> {
+    let token = Token {
+      d: self.direction,
+      k: self.keys.iter().map(|key| key.as_ref().map(encode_value).transpose()).collect::<Result<_, _>>()?,
+    };
+
+    let json = serde_json::to_vec(&token).map_err(|err| DbErr::Custom(err.to_string()))?;
+    Ok(URL_SAFE_NO_PAD.encode(json))
+  }
+
+  pub fn decode(cursor: &str) -> Result<Self, QueryError> {
+    let token = URL_SAFE_NO_PAD.decode(cursor)
+      .ok()
+      .and_then(|json| serde_json::from_slice::<Token>(&json).ok())
+      .ok_or_else(|| invalid_cursor(cursor))?;
+
+    let keys = token.k.iter()
+      .map(|key| match key {
+        Some((tag, value)) => decode_value(tag, value).map(Some).ok_or_else(|| invalid_cursor(cursor)),
+        None => Ok(None),
+      })
+      .collect::<Result<_, _>>()?;
+
+    Ok(Self {
+      direction: token.d,
+      keys,
+    })
+  }
+}
+
+pub(crate) fn invalid_cursor(cursor: &str) -> QueryError {
+  QueryError::InvalidCursor(cursor.to_string())
+}
+
+pub(crate) fn cursor_size(size: u64) -> Result<u64, QueryError> {
+  match size {
+    0 => Err(QueryError::InvalidPageSize(size)),
+    size => Ok(size.min(MAX_CURSOR_SIZE)),
+  }
+}
+
+pub(crate) fn cursor_key(value: Value) -> Option<Value> {
+  if value == value.as_null() {
+    None
+  } else {
+    Some(value)
+  }
+}
+
+fn encode_value(value: &Value) -> Result<(String, String), QueryError> {
+  let (tag, value) = match value {
+    Value::Bool(Some(v)) => ("bool", v.to_string()),
+    Value::TinyInt(Some(v)) => ("i8", v.to_string()),
+    Value::SmallInt(Some(v)) => ("i16", v.to_string()),
+    Value::Int(Some(v)) => ("i32", v.to_string()),
+    Value::BigInt(Some(v)) => ("i64", v.to_string()),
+    Value::TinyUnsigned(Some(v)) => ("u8", v.to_string()),
+    Value::SmallUnsigned(Some(v)) => ("u16", v.to_string()),
+    Value::Unsigned(Some(v)) => ("u32", v.to_string()),
+    Value::BigUnsigned(Some(v)) => ("u64", v.to_string()),
+    Value::Float(Some(v)) => ("f32", v.to_string()),
+    Value::Double(Some(v)) => ("f64", v.to_string()),
+    Value::String(Some(v)) => ("str", v.to_string()),
+    Value::Char(Some(v)) => ("char", v.to_string()),
+    Value::ChronoDate(Some(v)) => ("date", v.to_string()),
+    Value::ChronoTime(Some(v)) => ("time", v.to_string()),
+    Value::ChronoDateTime(Some(v)) => ("datetime", v.format(DATE_TIME_FORMAT).to_string()),
+    Value::ChronoDateTimeUtc(Some(v)) => ("utc", v.to_rfc3339()),
+    Value::ChronoDateTimeWithTimeZone(Some(v)) => ("tz", v.to_rfc3339()),
+    Value::Uuid(Some(v)) => ("uuid", v.to_string()),
+    Value::Decimal(Some(v)) => ("decimal", v.to_string()),
+    value => return Err(QueryError::Db(DbErr::Custom(format!("Unsupported cursor key value {:?}", value)))),
+  };
+
+  Ok((tag.to_string(), value))
+}
+
+fn decode_value(tag: &str, value: &str) -> Option<Value> {
+  let value = match tag {
+    "bool" => Value::from(value.parse::<bool>().ok()?),
+    "i8" => Value::from(value.parse::<i8>().ok()?),
+    "i16" => Value::from(value.parse::<i16>().ok()?),
+    "i32" => Value::from(value.parse::<i32>().ok()?),
+    "i64" => Value::from(value.parse::<i64>().ok()?),
+    "u8" => Value::from(value.parse::<u8>().ok()?),
+    "u16" => Value::from(value.parse::<u16>().ok()?),
+    "u32" => Value::from(value.parse::<u32>().ok()?),
+    "u64" => Value::from(value.parse::<u64>().ok()?),
+    "f32" => Value::from(value.parse::<f32>().ok()?),
+    "f64" => Value::from(value.parse::<f64>().ok()?),
+    "str" => Value::from(value.to_string()),
+    "char" => Value::from(value.parse::<char>().ok()?),
+    "date" => Value::from(value.parse::<ChronoDate>().ok()?),
+    "time" => Value::from(value.parse::<ChronoTime>().ok()?),
+    "datetime" => Value::from(ChronoDateTime::parse_from_str(value, DATE_TIME_FORMAT).ok()?),
+    "utc" => Value::from(ChronoDateTimeWithTimeZone::parse_from_rfc3339(value).ok()?.with_timezone(&chrono::Utc)),
+    "tz" => Value::from(ChronoDateTimeWithTimeZone::parse_from_rfc3339(value).ok()?),
+    "uuid" => Value::from(Uuid::parse_str(value).ok()?),
+    "decimal" => Value::from(Decimal::from_str(value).ok()?),
+    _ => return None,
+  };
+
+  Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+  use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+  use base64::Engine;
+  use sea_orm::prelude::{ChronoDate, ChronoDateTime, ChronoDateTimeWithTimeZone, ChronoTime, Decimal, Uuid};
+  use actix_web::http::StatusCode;
+  use actix_web::ResponseError;
+  use sea_orm::Value;
+  use crate::repository::QueryError;
+  use super::{cursor_key, cursor_size, Cursor, CursorDirection, MAX_CURSOR_SIZE};
+
+  fn round_trip(keys: Vec<Option<Value>>) {
+    let cursor = Cursor {
+      direction: CursorDirection::Prev,
+      keys,
+    };
+
+    assert_eq!(Cursor::decode(&cursor.encode().unwrap()).unwrap(), cursor);
+  }
+
+  #[test]
+  fn round_trips_every_supported_value() {
+    let date_time = ChronoDateTime::parse_from_str("2024-02-29T13:45:30.123456", "%Y-%m-%dT%H:%M:%S%.f").unwrap();
+    let zoned = ChronoDateTimeWithTimeZone::parse_from_rfc3339("2024-02-29T13:45:30.5+02:00").unwrap();
+
+    let values = [
+      Value::from(true),
+      Value::from(-8i8),
+      Value::from(-16i16),
+      Value::from(-32i32),
+      Value::from(i64::MIN),
+      Value::from(8u8),
+      Value::from(16u16),
+      Value::from(32u32),
+      Value::from(u64::MAX),
+      Value::from(1.5f32),
+      Value::from(-0.1f64),
+      Value::from("a,b \"quoted\" ✓".to_string()),
+      Value::from('✓'),
+      Value::from(ChronoDate::from_ymd_opt(2024, 2, 29).unwrap()),
+      Value::from(ChronoTime::from_hms_micro_opt(23, 59, 59, 250).unwrap()),
+      Value::from(date_time),
+      Value::from(zoned.with_timezone(&chrono::Utc)),
+      Value::from(zoned),
+      Value::from(Uuid::from_u128(0x1234_5678_9abc_def0_1234_5678_9abc_def0)),
+      Value::from("-12.3400".parse::<Decimal>().unwrap()),
+    ];
+
+    for value in values {
+      round_trip(vec![Some(value)]);
+    }
+  }
+
+  #[test]
+  fn round_trips_null_keys() {
+    round_trip(vec![None, Some(Value::from(7i32))]);
+  }
+
+  #[test]
+  fn treats_null_values_as_missing_keys() {
+    assert_eq!(cursor_key(Value::Int(None)), None);
+    assert_eq!(cursor_key(Value::String(None)), None);
+    assert_eq!(cursor_key(Value::from(0i32)), Some(Value::from(0i32)));
+  }
+
+  #[test]
+  fn keeps_direction() {
+    let cursor = Cursor {
+      direction: CursorDirection::Next,
+      keys: vec![Some(Value::from(1i32))],
+    };
+
+    assert_eq!(Cursor::decode(&cursor.encode().unwrap()).unwrap().direction, CursorDirection::Next);
+  }
+
+  #[test]
+  fn encodes_url_safe() {
+    let cursor = Cursor {
+      direction: CursorDirection::Next,
+      keys: vec![Some(Value::from("???>>>".to_string()))],
+    };
+
+    let encoded = cursor.encode().unwrap();
+
+    assert!(encoded.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'));
+  }
+
+  #[test]
+  fn rejects_malformed_cursors() {
+    let unknown_tag = URL_SAFE_NO_PAD.encode(r#"{"d":"n","k":[["blob","x"]]}"#);
+    let bad_value = URL_SAFE_NO_PAD.encode(r#"{"d":"n","k":[["i32","x"]]}"#);
+
+    for cursor in ["", "not a cursor", "e30", unknown_tag.as_str(), bad_value.as_str()] {
+      assert!(matches!(Cursor::decode(cursor), Err(QueryError::InvalidCursor(value)) if value == cursor));
+    }
+  }
+
+  #[test]
+  fn rejects_unsupported_values() {
+    let cursor = Cursor {
+      direction: CursorDirection::Next,
+      keys: vec![Some(Value::Bytes(Some(Box::new(vec![1]))))],
+    };
+
+    assert!(matches!(cursor.encode(), Err(QueryError::Db(_))));
+  }
+
+  #[test]
+  fn reports_invalid_cursor_as_bad_request() {
+    let err = Cursor::decode("not a cursor").unwrap_err();
+
+    assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+    assert_eq!(err.error_response().status(), StatusCode::BAD_REQUEST);
+  }
+
+  #[test]
+  fn rejects_empty_pages() {
+    let err = cursor_size(0).unwrap_err();
+
+    assert!(matches!(err, QueryError::InvalidPageSize(0)));
+    assert_eq!(err.status_code(), StatusCode::BAD_REQUEST);
+  }
+
+  #[test]
+  fn clamps_page_size() {
+    assert_eq!(cursor_size(1).unwrap(), 1);
+    assert_eq!(cursor_size(MAX_CURSOR_SIZE + 1).unwrap(), MAX_CURSOR_SIZE);
+    assert_eq!(cursor_size(u64::MAX).unwrap(), MAX_CURSOR_SIZE);
+  }
+}
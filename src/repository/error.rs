@@ -0,0 +1,62 @@
+use std::fmt::{Display, Formatter};
+use actix_web::http::StatusCode;
+use actix_web::{HttpResponse, ResponseError};
+use sea_orm::DbErr;
+use crate::validation::ErrorBody;
+
+#[derive(Debug)]
+pub enum QueryError {
+  InvalidSort(String),
+  InvalidCursor(String),
+  InvalidPageSize(u64),
+  Db(DbErr),
+}
+
+impl Display for QueryError {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      QueryError::InvalidSort(property) => write!(f, "Unknown sort property '{}'", property),
+      QueryError::InvalidCursor(cursor) => write!(f, "Invalid cursor '{}'", cursor),
+      QueryError::InvalidPageSize(size) => write!(f, "Invalid page size {}", size),
+      QueryError::Db(err) => write!(f, "{}", err),
+    }
+  }
+}
+
+impl std::error::Error for QueryError {}
+
+impl From<DbErr> for QueryError {
+  fn from(err: DbErr) -> Self {
+    QueryError::Db(err)
+  }
+}
+
+impl From<QueryError> for DbErr {
+  fn from(err: QueryError) -> Self {
+    match err {
+      QueryError::Db(err) => err,
+      err => DbErr::Custom(err.to_string()),
+    }
+  }
+}
+
+impl ResponseError for QueryError {
+  fn status_code(&self) -> StatusCode {
+    match self {
+      QueryError::Db(_) => StatusCode::INTERNAL_SERVER_ERROR,
+      _ => StatusCode::BAD_REQUEST,
+    }
+  }
+
+  fn error_response(&self) -> HttpResponse {
+    let message = match self {
+      QueryError::Db(err) => {
+        log::error!("{}", err);
+        "Database query failed".to_string()
+      }
+      err => err.to_string(),
+    };
+
+    HttpResponse::build(self.status_code()).json(ErrorBody::new(self.status_code(), message, vec![]))
+  }
+}
@@ -1,17 +1,56 @@
 use std::any::Any;
+use sea_orm::DeleteResult;
 
 pub mod macros;
+pub mod operations;
+mod cursor;
+mod error;
+mod pageable;
+
+pub use cursor::{CursorPage, MAX_CURSOR_SIZE};
+pub use error::QueryError;
+pub use pageable::{Direction, Order, Pageable, PageableConfig, PageableRejection, Sort};
 
 type SeaResult<T> = std::result::Result<T, sea_orm::DbErr>;
 
 pub trait Repository: Any + Send + Sync {
   type Model;
 
+  type Id: Send;
+}
+
+pub trait ReadRepository: Repository {
   fn find_all(&self) -> impl Future<Output = SeaResult<Vec<Self::Model>>> + Send;
 
-  fn find(&self, id: i32) -> impl Future<Output = SeaResult<Option<Self::Model>>> + Send;
+  fn find(&self, id: Self::Id) -> impl Future<Output = SeaResult<Option<Self::Model>>> + Send;
+
+  fn exists(&self, id: Self::Id) -> impl Future<Output = SeaResult<bool>> + Send;
+
+  fn count(&self) -> impl Future<Output = SeaResult<u64>> + Send;
+}
+
+pub trait PagingRepository: ReadRepository {
+  fn find_page(&self, page: u64, per_page: u64) -> impl Future<Output = SeaResult<Paginator<Self::Model>>> + Send;
+
+  fn find_all_sorted(&self, sort: &Sort) -> impl Future<Output = Result<Vec<Self::Model>, QueryError>> + Send;
+
+  fn find_all_pageable(&self, pageable: &Pageable) -> impl Future<Output = Result<Paginator<Self::Model>, QueryError>> + Send;
+}
+
+pub trait CrudRepository: ReadRepository {
+  type ActiveModel: Send;
+
+  fn save(&self, model: Self::ActiveModel) -> impl Future<Output = SeaResult<Self::Model>> + Send;
+
+  fn insert(&self, model: Self::ActiveModel) -> impl Future<Output = SeaResult<Self::Model>> + Send;
+
+  fn save_all(&self, models: Vec<Self::ActiveModel>) -> impl Future<Output = SeaResult<Vec<Self::Model>>> + Send;
+
+  fn delete_by_id(&self, id: Self::Id) -> impl Future<Output = SeaResult<DeleteResult>> + Send;
+
+  fn delete_all_by_ids(&self, ids: Vec<Self::Id>) -> impl Future<Output = SeaResult<DeleteResult>> + Send;
 
-  fn exists(&self, id: i32) -> impl Future<Output = SeaResult<bool>> + Send;
+  fn delete_all(&self) -> impl Future<Output = SeaResult<DeleteResult>> + Send;
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
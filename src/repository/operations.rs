@@ -0,0 +1,367 @@
+use sea_orm::sea_query::{FromValueTuple, IntoValueTuple, NullOrdering};
+use sea_orm::{
+  ActiveModelBehavior, ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DeleteResult, EntityTrait, IdenStatic,
+  IntoActiveModel, Iterable, ModelTrait, Order, PaginatorTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, QueryOrder, QuerySelect, Select,
+  TransactionTrait,
+};
+use crate::repository::cursor::{cursor_key, cursor_size, invalid_cursor, Cursor, CursorDirection};
+use crate::repository::{CursorPage, Direction, Pageable, Paginator, QueryError, Sort};
+
+type SeaResult<T> = Result<T, sea_orm::DbErr>;
+
+type ModelOf<A> = <<A as ActiveModelTrait>::Entity as EntityTrait>::Model;
+
+type IdOf<E> = <<E as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType;
+
+pub fn apply_sort<E>(mut select: Select<E>, sort: &Sort) -> Result<Select<E>, QueryError>
+where
+  E: EntityTrait,
+{
+  for order in sort.iter() {
+    let column = order.property
+      .parse::<E::Column>()
+      .map_err(|_| QueryError::InvalidSort(order.property.clone()))?;
+
+    let direction = match order.direction {
+      Direction::Asc => sea_orm::Order::Asc,
+      Direction::Desc => sea_orm::Order::Desc,
+    };
+
+    select = select.order_by(column, direction);
+  }
+
+  Ok(select)
+}
+
+pub async fn find_pageable<E, C>(db: &C, select: Select<E>, pageable: &Pageable) -> Result<Paginator<E::Model>, QueryError>
+where
+  E: EntityTrait,
+  E::Model: Sync,
+  C: ConnectionTrait,
+{
+  Ok(find_page(db, apply_sort(select, &pageable.sort)?, pageable.page, pageable.size).await?)
+}
+
+pub async fn find_page<E, C>(db: &C, select: Select<E>, page: u64, per_page: u64) -> SeaResult<Paginator<E::Model>>
+where
+  E: EntityTrait,
+  E::Model: Sync,
+  C: ConnectionTrait,
+{
+  let total_items = select.clone().count(db).await?;
+
+  let total_pages = if per_page > 0 {
+    total_items.div_ceil(per_page)
+  } else {
+    0
+  };
+
+  let items = select
+    .offset(page.saturating_sub(1) * per_page)
+    .limit(per_page)
+    .all(db)
+    .await?;
+
+  Ok(Paginator {
+    items,
+    page,
+    per_page,
+    total_items,
+    total_pages,
+  })
+}
+
+pub async fn find_cursor<E, C>(
+  db: &C,
+  select: Select<E>,
+  orders: Vec<(E::Column, Order)>,
+  cursor: Option<&str>,
+  size: u64,
+) -> Result<CursorPage<E::Model>, QueryError>
+where
+  E: EntityTrait,
+  C: ConnectionTrait,
+{
+  let size = cursor_size(size)?;
+  let orders = keyset_orders::<E>(orders);
+
+  let position = match cursor {
+    Some(cursor) => {
+      let position = Cursor::decode(cursor)?;
+
+      if position.keys.len() != orders.len() {
+        return Err(invalid_cursor(cursor));
+      }
+
+      Some(position)
+    }
+    None => None,
+  };
+
+  let backward = matches!(&position, Some(position) if position.direction == CursorDirection::Prev);
+  let mut select = select;
+
+  if let Some(position) = &position {
+    select = select.filter(keyset_condition(&orders, &position.keys, backward));
+  }
+
+  for (column, order) in &orders {
+    let ascending = matches!(order, Order::Asc) != backward;
+    let direction = if ascending { Order::Asc } else { Order::Desc };
+
+    select = if column.def().is_null() {
+      select.order_by_with_nulls(*column, direction, if backward { NullOrdering::First } else { NullOrdering::Last })
+    } else {
+      select.order_by(*column, direction)
+    };
+  }
+
+  let mut items = select.limit(size + 1).all(db).await?;
+  let has_more = items.len() as u64 > size;
+
+  items.truncate(size as usize);
+
+  if backward {
+    items.reverse();
+  }
+
+  let (has_next, has_prev) = match position {
+    None => (has_more, false),
+    Some(_) if backward => (true, has_more),
+    Some(_) => (has_more, true),
+  };
+
+  let next_cursor = match items.last() {
+    Some(item) if has_next => Some(keyset_cursor(&orders, item, CursorDirection::Next)?),
+    _ => None,
+  };
+
+  let prev_cursor = match items.first() {
+    Some(item) if has_prev => Some(keyset_cursor(&orders, item, CursorDirection::Prev)?),
+    _ => None,
+  };
+
+  Ok(CursorPage {
+    items,
+    size,
+    next_cursor,
+    prev_cursor,
+  })
+}
+
+fn keyset_orders<E>(mut orders: Vec<(E::Column, Order)>) -> Vec<(E::Column, Order)>
+where
+  E: EntityTrait,
+{
+  for key in E::PrimaryKey::iter() {
+    let column = key.into_column();
+
+    if !orders.iter().any(|(ordered, _)| ordered.as_str() == column.as_str()) {
+      orders.push((column, Order::Asc));
+    }
+  }
+
+  orders
+}
+
+fn keyset_condition<C>(orders: &[(C, Order)], keys: &[Option<sea_orm::Value>], backward: bool) -> Condition
+where
+  C: ColumnTrait,
+{
+  // Nulls sort after every value going forward, so a null key is only ever
+  // followed by other nulls and preceded by any non-null value.
+  (0..orders.len()).fold(Condition::any(), |condition, index| {
+    let preceding = orders[..index].iter()
+      .zip(keys)
+      .fold(Condition::all(), |preceding, ((column, _), key)| preceding.add(match key {
+        Some(key) => column.eq(key.clone()),
+        None => column.is_null(),
+      }));
+
+    let (column, order) = &orders[index];
+    let ascending = matches!(order, Order::Asc) != backward;
+
+    let beyond = match &keys[index] {
+      Some(key) => {
+        let beyond = if ascending {
+          column.gt(key.clone())
+        } else {
+          column.lt(key.clone())
+        };
+
+        if !backward && column.def().is_null() {
+          Condition::any().add(beyond).add(column.is_null())
+        } else {
+          Condition::all().add(beyond)
+        }
+      }
+      None if backward => Condition::all().add(column.is_not_null()),
+      None => return condition,
+    };
+
+    condition.add(preceding.add(beyond))
+  })
+}
+
+fn keyset_cursor<M>(orders: &[(<M::Entity as EntityTrait>::Column, Order)], item: &M, direction: CursorDirection) -> Result<String, QueryError>
+where
+  M: ModelTrait,
+{
+  Cursor {
+    direction,
+    keys: orders.iter().map(|(column, _)| cursor_key(item.get(*column))).collect(),
+  }.encode()
+}
+
+pub async fn save<A, C>(db: &C, model: A) -> SeaResult<ModelOf<A>>
+where
+  A: ActiveModelTrait + ActiveModelBehavior + Send,
+  ModelOf<A>: IntoActiveModel<A>,
+  C: ConnectionTrait,
+{
+  let exists = match model.get_primary_key_value() {
+    Some(key) => {
+      let id = IdOf::<A::Entity>::from_value_tuple(key);
+      A::Entity::find_by_id(id).one(db).await?.is_some()
+    }
+    None => false,
+  };
+
+  if exists {
+    model.update(db).await
+  } else {
+    model.insert(db).await
+  }
+}
+
+pub async fn save_all<A, C>(db: &C, models: Vec<A>) -> SeaResult<Vec<ModelOf<A>>>
+where
+  A: ActiveModelTrait + ActiveModelBehavior + Send,
+  ModelOf<A>: IntoActiveModel<A>,
+  C: ConnectionTrait + TransactionTrait,
+{
+  let transaction = db.begin().await?;
+  let mut saved = Vec::with_capacity(models.len());
+
+  for model in models {
+    saved.push(save(&transaction, model).await?);
+  }
+
+  transaction.commit().await?;
+  Ok(saved)
+}
+
+pub async fn delete_all_by_ids<E, C>(db: &C, ids: Vec<IdOf<E>>) -> SeaResult<DeleteResult>
+where
+  E: EntityTrait,
+  C: ConnectionTrait,
+{
+  if ids.is_empty() {
+    return Ok(DeleteResult { rows_affected: 0 });
+  }
+
+  let condition = ids.into_iter().fold(Condition::any(), |condition, id| {
+    let matches_id = E::PrimaryKey::iter()
+      .zip(id.into_value_tuple())
+      .fold(Condition::all(), |matches_id, (key, value)| matches_id.add(key.into_column().eq(value)));
+
+    condition.add(matches_id)
+  });
+
+  E::delete_many().filter(condition).exec(db).await
+}
+
+#[cfg(test)]
+mod tests {
+  use sea_orm::{DbBackend, EntityTrait, IdenStatic, Order, QueryFilter, QueryTrait, Value};
+  use super::{keyset_condition, keyset_orders};
+
+  mod item {
+    use sea_orm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[sea_orm(table_name = "item")]
+    pub struct Model {
+      #[sea_orm(primary_key)]
+      pub id: i32,
+      pub rank: i32,
+      pub score: Option<i32>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+  }
+
+  use item::Column;
+
+  fn sql(orders: &[(Column, Order)], keys: &[Option<Value>], backward: bool) -> String {
+    let sql = item::Entity::find()
+      .filter(keyset_condition(orders, keys, backward))
+      .build(DbBackend::Postgres)
+      .to_string();
+
+    sql.split(" WHERE ").nth(1).unwrap().to_string()
+  }
+
+  fn names(orders: Vec<(Column, Order)>) -> Vec<String> {
+    orders.into_iter().map(|(column, order)| format!("{} {:?}", column.as_str(), order)).collect()
+  }
+
+  #[test]
+  fn appends_primary_key_tie_breaker() {
+    assert_eq!(names(keyset_orders::<item::Entity>(vec![(Column::Rank, Order::Desc)])), ["rank Desc", "id Asc"]);
+    assert_eq!(names(keyset_orders::<item::Entity>(vec![(Column::Id, Order::Desc)])), ["id Desc"]);
+  }
+
+  #[test]
+  fn mixed_directions_forward() {
+    let orders = [(Column::Rank, Order::Desc), (Column::Id, Order::Asc)];
+    let keys = [Some(Value::from(5)), Some(Value::from(9))];
+
+    assert_eq!(
+      sql(&orders, &keys, false),
+      r#""item"."rank" < 5 OR ("item"."rank" = 5 AND "item"."id" > 9)"#,
+    );
+  }
+
+  #[test]
+  fn mixed_directions_backward() {
+    let orders = [(Column::Rank, Order::Desc), (Column::Id, Order::Asc)];
+    let keys = [Some(Value::from(5)), Some(Value::from(9))];
+
+    assert_eq!(
+      sql(&orders, &keys, true),
+      r#""item"."rank" > 5 OR ("item"."rank" = 5 AND "item"."id" < 9)"#,
+    );
+  }
+
+  #[test]
+  fn nullable_key_forward() {
+    let orders = [(Column::Score, Order::Asc), (Column::Id, Order::Asc)];
+
+    assert_eq!(
+      sql(&orders, &[Some(Value::from(5)), Some(Value::from(9))], false),
+      r#""item"."score" > 5 OR "item"."score" IS NULL OR ("item"."score" = 5 AND "item"."id" > 9)"#,
+    );
+    assert_eq!(
+      sql(&orders, &[None, Some(Value::from(9))], false),
+      r#""item"."score" IS NULL AND "item"."id" > 9"#,
+    );
+  }
+
+  #[test]
+  fn nullable_key_backward() {
+    let orders = [(Column::Score, Order::Asc), (Column::Id, Order::Asc)];
+
+    assert_eq!(
+      sql(&orders, &[Some(Value::from(5)), Some(Value::from(9))], true),
+      r#""item"."score" < 5 OR ("item"."score" = 5 AND "item"."id" < 9)"#,
+    );
+    assert_eq!(
+      sql(&orders, &[None, Some(Value::from(9))], true),
+      r#""item"."score" IS NOT NULL OR ("item"."score" IS NULL AND "item"."id" < 9)"#,
+    );
+  }
+}
@@ -0,0 +1,282 @@
+use std::fmt::{Display, Formatter};
+use std::future::{ready, Ready};
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::web::Query;
+use actix_web::{FromRequest, HttpRequest, HttpResponse, ResponseError};
+use serde::{Deserialize, Serialize};
+use crate::validation::ErrorBody;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+  Asc,
+  Desc,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Order {
+  pub property: String,
+  pub direction: Direction,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Sort {
+  pub orders: Vec<Order>,
+}
+
+impl Sort {
+  pub fn unsorted() -> Self {
+    Self::default()
+  }
+
+  pub fn by(property: impl Into<String>, direction: Direction) -> Self {
+    Self::unsorted().and(property, direction)
+  }
+
+  pub fn and(mut self, property: impl Into<String>, direction: Direction) -> Self {
+    self.orders.push(Order {
+      property: property.into(),
+      direction,
+    });
+    self
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.orders.is_empty()
+  }
+
+  pub fn iter(&self) -> impl Iterator<Item = &Order> {
+    self.orders.iter()
+  }
+
+  fn parse(values: &[&str]) -> Result<Self, PageableRejection> {
+    let mut sort = Sort::unsorted();
+
+    for value in values {
+      let mut parts = value.split(',').map(str::trim).filter(|part| !part.is_empty()).collect::<Vec<_>>();
+
+      let direction = match parts.last().map(|part| part.to_ascii_lowercase()) {
+        Some(last) if last == "asc" => {
+          parts.pop();
+          Direction::Asc
+        }
+        Some(last) if last == "desc" => {
+          parts.pop();
+          Direction::Desc
+        }
+        _ => Direction::Asc,
+      };
+
+      if parts.is_empty() {
+        return Err(PageableRejection(format!("Invalid sort '{}': expected property[,asc|desc]", value)));
+      }
+
+      for property in parts {
+        sort = sort.and(property, direction);
+      }
+    }
+
+    Ok(sort)
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Pageable {
+  pub page: u64,
+  pub size: u64,
+  pub sort: Sort,
+}
+
+impl Pageable {
+  pub fn of(page: u64, size: u64) -> Self {
+    Self {
+      page,
+      size,
+      sort: Sort::unsorted(),
+    }
+  }
+
+  pub fn with_sort(mut self, sort: Sort) -> Self {
+    self.sort = sort;
+    self
+  }
+
+  pub fn offset(&self) -> u64 {
+    self.page.saturating_sub(1) * self.size
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct PageableConfig {
+  pub default_page: u64,
+  pub default_size: u64,
+  pub max_size: u64,
+}
+
+impl PageableConfig {
+  pub fn default_size(mut self, size: u64) -> Self {
+    self.default_size = size;
+    self
+  }
+
+  pub fn max_size(mut self, size: u64) -> Self {
+    self.max_size = size;
+    self
+  }
+
+  fn parse(&self, query: &str) -> Result<Pageable, PageableRejection> {
+    let pairs = Query::<Vec<(String, String)>>::from_query(query)
+      .map_err(|err| PageableRejection(err.to_string()))?
+      .into_inner();
+
+    let value = |key: &str| pairs.iter().rev().find(|(name, _)| name == key).map(|(_, value)| value.as_str());
+
+    let page = match value("page") {
+      Some(page) => parse_number("page", page)?,
+      None => self.default_page,
+    };
+
+    let size = match value("size") {
+      Some(size) => parse_number("size", size)?,
+      None => self.default_size,
+    };
+
+    let sorts = pairs.iter()
+      .filter(|(name, _)| name == "sort")
+      .map(|(_, value)| value.as_str())
+      .collect::<Vec<_>>();
+
+    Ok(Pageable {
+      page,
+      size: size.min(self.max_size),
+      sort: Sort::parse(&sorts)?,
+    })
+  }
+}
+
+impl Default for PageableConfig {
+  fn default() -> Self {
+    Self {
+      default_page: 1,
+      default_size: 20,
+      max_size: 100,
+    }
+  }
+}
+
+fn parse_number(name: &str, value: &str) -> Result<u64, PageableRejection> {
+  match value.parse::<u64>() {
+    Ok(number) if number > 0 => Ok(number),
+    _ => Err(PageableRejection(format!("Invalid {} '{}': expected a positive integer", name, value))),
+  }
+}
+
+impl FromRequest for Pageable {
+  type Error = PageableRejection;
+  type Future = Ready<Result<Self, Self::Error>>;
+
+  fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+    let config = req.app_data::<PageableConfig>().cloned().unwrap_or_default();
+    ready(config.parse(req.query_string()))
+  }
+}
+
+impl FromRequest for Sort {
+  type Error = PageableRejection;
+  type Future = Ready<Result<Self, Self::Error>>;
+
+  fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+    let pageable = Pageable::from_request(req, payload).into_inner();
+    ready(pageable.map(|pageable| pageable.sort))
+  }
+}
+
+#[derive(Debug)]
+pub struct PageableRejection(String);
+
+impl Display for PageableRejection {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    f.write_str(&self.0)
+  }
+}
+
+impl ResponseError for PageableRejection {
+  fn status_code(&self) -> StatusCode {
+    StatusCode::BAD_REQUEST
+  }
+
+  fn error_response(&self) -> HttpResponse {
+    HttpResponse::build(self.status_code()).json(ErrorBody::new(self.status_code(), self.to_string(), vec![]))
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{Direction, PageableConfig, Sort};
+
+  #[test]
+  fn sort_defaults_to_ascending() {
+    assert_eq!(Sort::parse(&["title"]).unwrap(), Sort::by("title", Direction::Asc));
+  }
+
+  #[test]
+  fn sort_direction_applies_to_every_property() {
+    assert_eq!(
+      Sort::parse(&["a,b,desc"]).unwrap(),
+      Sort::by("a", Direction::Desc).and("b", Direction::Desc),
+    );
+  }
+
+  #[test]
+  fn sort_direction_is_case_insensitive() {
+    assert_eq!(Sort::parse(&["title, DESC"]).unwrap(), Sort::by("title", Direction::Desc));
+  }
+
+  #[test]
+  fn sort_combines_repeated_values() {
+    assert_eq!(
+      Sort::parse(&["title,desc", "id"]).unwrap(),
+      Sort::by("title", Direction::Desc).and("id", Direction::Asc),
+    );
+  }
+
+  #[test]
+  fn sort_rejects_direction_without_property() {
+    assert!(Sort::parse(&[",desc"]).is_err());
+    assert!(Sort::parse(&[""]).is_err());
+  }
+
+  #[test]
+  fn pageable_uses_defaults() {
+    let pageable = PageableConfig::default().parse("").unwrap();
+
+    assert_eq!((pageable.page, pageable.size), (1, 20));
+    assert!(pageable.sort.is_empty());
+  }
+
+  #[test]
+  fn pageable_clamps_size() {
+    let pageable = PageableConfig::default().max_size(50).parse("page=3&size=500").unwrap();
+
+    assert_eq!((pageable.page, pageable.size), (3, 50));
+    assert_eq!(pageable.offset(), 100);
+  }
+
+  #[test]
+  fn pageable_prefers_last_value() {
+    let pageable = PageableConfig::default().parse("page=2&page=4&sort=id,desc&sort=title").unwrap();
+
+    assert_eq!(pageable.page, 4);
+    assert_eq!(pageable.sort, Sort::by("id", Direction::Desc).and("title", Direction::Asc));
+  }
+
+  #[test]
+  fn pageable_rejects_invalid_numbers() {
+    let config = PageableConfig::default();
+
+    assert!(config.parse("page=0").is_err());
+    assert!(config.parse("size=-1").is_err());
+    assert!(config.parse("page=abc").is_err());
+  }
+}
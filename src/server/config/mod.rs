@@ -1,7 +1,8 @@
+use std::any::TypeId;
 use std::sync::Arc;
 use actix_web::web::{Data, ServiceConfig};
-use sea_orm::{Database, DatabaseConnection};
-use crate::di::DIContext;
+use sea_orm::DatabaseConnection;
+use crate::di::{DIContext, Scope};
 use crate::repository::Repository;
 use crate::server::config::registry::{RepositoryRegistrator, ServiceRegistrator};
 use crate::service::Service;
@@ -18,6 +19,10 @@ impl ServiceRegistrator for ApplicationServerConfigurer<'_> {
   where
     S: Service
   {
+    if S::SCOPE != Scope::Singleton || self.context.is_disabled(TypeId::of::<S>()) {
+      return;
+    }
+
     let service = self.context.get_service::<S>();
     self.service_config.app_data(Data::from(service));
   }
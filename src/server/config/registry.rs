@@ -1,6 +1,6 @@
 use crate::repository::Repository;
 use crate::service::Service;
-use sea_orm::{Database, DatabaseConnection};
+use sea_orm::DatabaseConnection;
 
 pub trait ServiceRegistrator {
   fn register<S>(&mut self)
@@ -0,0 +1 @@
+pub use actix_boot_service_macros::lifecycle;
\ No newline at end of file
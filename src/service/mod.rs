@@ -1,9 +1,52 @@
-use crate::di::{DIContext};
+use crate::di::{BoxError, DIContext, DIError, Dependency, Scope};
 use std::any::Any;
-use crate::server::config::ApplicationServerConfigurer;
+use std::future::Future;
 
 pub mod derive;
+pub mod macros;
 
 pub trait Service: Any + Send + Sync {
+  const SCOPE: Scope = Scope::Singleton;
+
+  const HAS_INIT: bool = false;
+
   fn new_service(context: &DIContext) -> Self;
+
+  fn try_new_service(context: &DIContext) -> Result<Self, DIError>
+  where
+    Self: Sized,
+  {
+    Ok(Self::new_service(context))
+  }
+
+  fn dependencies() -> Vec<Dependency> {
+    Vec::new()
+  }
+
+  fn init(&self) -> impl Future<Output = Result<(), BoxError>> + Send {
+    std::future::ready(Ok(()))
+  }
+
+  fn post_construct(&self) {}
+
+  fn pre_destroy(&self) -> impl Future<Output = Result<(), BoxError>> + Send {
+    std::future::ready(Ok(()))
+  }
 }
+
+#[doc(hidden)]
+pub trait LifecycleFallback {
+  const __ACTIX_BOOT_HAS_INIT: bool = false;
+
+  fn __actix_boot_init(&self) -> impl Future<Output = Result<(), BoxError>> + Send {
+    std::future::ready(Ok(()))
+  }
+
+  fn __actix_boot_post_construct(&self) {}
+
+  fn __actix_boot_pre_destroy(&self) -> impl Future<Output = Result<(), BoxError>> + Send {
+    std::future::ready(Ok(()))
+  }
+}
+
+impl<T: ?Sized> LifecycleFallback for T {}
@@ -0,0 +1,259 @@
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::future::Future;
+use std::ops::{Deref, DerefMut};
+use std::pin::Pin;
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::web::{Json, Path, Query};
+use actix_web::{FromRequest, HttpRequest, HttpResponse, ResponseError};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use validator::{ValidationErrors, ValidationErrorsKind};
+
+pub use validator::{Validate, ValidationError};
+
+pub struct Valid<E>(pub E);
+
+impl<E> Valid<E> {
+  pub fn into_inner(self) -> E {
+    self.0
+  }
+}
+
+impl<E> Deref for Valid<E> {
+  type Target = E;
+
+  fn deref(&self) -> &Self::Target {
+    &self.0
+  }
+}
+
+impl<E> DerefMut for Valid<E> {
+  fn deref_mut(&mut self) -> &mut Self::Target {
+    &mut self.0
+  }
+}
+
+pub trait ValidatedExtractor: FromRequest {
+  type Target: Validate;
+
+  fn target(&self) -> &Self::Target;
+}
+
+impl<T> ValidatedExtractor for Json<T>
+where
+  T: DeserializeOwned + Validate + 'static,
+{
+  type Target = T;
+
+  fn target(&self) -> &Self::Target {
+    self
+  }
+}
+
+impl<T> ValidatedExtractor for Query<T>
+where
+  T: DeserializeOwned + Validate + 'static,
+{
+  type Target = T;
+
+  fn target(&self) -> &Self::Target {
+    self
+  }
+}
+
+impl<T> ValidatedExtractor for Path<T>
+where
+  T: DeserializeOwned + Validate + 'static,
+{
+  type Target = T;
+
+  fn target(&self) -> &Self::Target {
+    self
+  }
+}
+
+impl<E> FromRequest for Valid<E>
+where
+  E: ValidatedExtractor + 'static,
+{
+  type Error = ValidationRejection;
+  type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+  fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+    let extract = E::from_request(req, payload);
+
+    Box::pin(async move {
+      let extracted = extract
+        .await
+        .map_err(|err| ValidationRejection::Extract(err.into()))?;
+
+      extracted
+        .target()
+        .validate()
+        .map_err(ValidationRejection::Invalid)?;
+
+      Ok(Valid(extracted))
+    })
+  }
+}
+
+#[derive(Debug)]
+pub enum ValidationRejection {
+  Extract(actix_web::Error),
+  Invalid(ValidationErrors),
+}
+
+impl Display for ValidationRejection {
+  fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+    match self {
+      ValidationRejection::Extract(err) => write!(f, "{}", err),
+      ValidationRejection::Invalid(_) => f.write_str("Validation failed"),
+    }
+  }
+}
+
+impl ResponseError for ValidationRejection {
+  fn status_code(&self) -> StatusCode {
+    match self {
+      ValidationRejection::Extract(err) => err.as_response_error().status_code(),
+      ValidationRejection::Invalid(_) => StatusCode::UNPROCESSABLE_ENTITY,
+    }
+  }
+
+  fn error_response(&self) -> HttpResponse {
+    let errors = match self {
+      ValidationRejection::Extract(_) => vec![],
+      ValidationRejection::Invalid(errors) => FieldError::collect(errors),
+    };
+
+    HttpResponse::build(self.status_code()).json(ErrorBody::new(self.status_code(), self.to_string(), errors))
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ErrorBody {
+  pub status: u16,
+  pub error: String,
+  pub message: String,
+  pub errors: Vec<FieldError>,
+}
+
+impl ErrorBody {
+  pub fn new(status: StatusCode, message: impl Into<String>, errors: Vec<FieldError>) -> Self {
+    Self {
+      status: status.as_u16(),
+      error: status.canonical_reason().unwrap_or_default().to_string(),
+      message: message.into(),
+      errors,
+    }
+  }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct FieldError {
+  pub field: String,
+  pub code: String,
+  pub message: Option<String>,
+  pub params: HashMap<String, serde_json::Value>,
+}
+
+impl FieldError {
+  pub fn collect(errors: &ValidationErrors) -> Vec<FieldError> {
+    let mut result = Vec::new();
+    Self::collect_into(None, errors, &mut result);
+    result.sort_by(|a, b| a.field.cmp(&b.field));
+    result
+  }
+
+  fn collect_into(prefix: Option<&str>, errors: &ValidationErrors, result: &mut Vec<FieldError>) {
+    for (field, kind) in errors.errors() {
+      let path = match prefix {
+        Some(prefix) => format!("{}.{}", prefix, field),
+        None => field.to_string(),
+      };
+
+      match kind {
+        ValidationErrorsKind::Field(field_errors) => {
+          result.extend(field_errors.iter().map(|error| FieldError {
+            field: path.clone(),
+            code: error.code.to_string(),
+            message: error.message.as_ref().map(|message| message.to_string()),
+            params: error.params.iter()
+              .filter(|(name, _)| *name != "value")
+              .map(|(name, value)| (name.to_string(), value.clone()))
+              .collect(),
+          }));
+        }
+        ValidationErrorsKind::Struct(nested) => {
+          Self::collect_into(Some(&path), nested, result);
+        }
+        ValidationErrorsKind::List(items) => {
+          for (index, nested) in items {
+            Self::collect_into(Some(&format!("{}[{}]", path, index)), nested, result);
+          }
+        }
+      }
+    }
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use validator::Validate;
+  use super::FieldError;
+
+  #[derive(Validate)]
+  struct Author {
+    #[validate(length(min = 1))]
+    name: String,
+  }
+
+  #[derive(Validate)]
+  struct Tag {
+    #[validate(length(max = 4))]
+    name: String,
+  }
+
+  #[derive(Validate)]
+  struct Post {
+    #[validate(length(min = 3, message = "too short"))]
+    title: String,
+    #[validate(nested)]
+    author: Author,
+    #[validate(nested)]
+    tags: Vec<Tag>,
+  }
+
+  #[test]
+  fn collects_nested_errors_sorted_by_path() {
+    let post = Post {
+      title: "ab".to_string(),
+      author: Author { name: String::new() },
+      tags: vec![Tag { name: "rust".to_string() }, Tag { name: "actix-web".to_string() }],
+    };
+
+    let errors = FieldError::collect(&post.validate().unwrap_err());
+    let fields = errors.iter().map(|error| error.field.as_str()).collect::<Vec<_>>();
+
+    assert_eq!(fields, ["author.name", "tags[1].name", "title"]);
+    assert!(errors.iter().all(|error| error.code == "length"));
+  }
+
+  #[test]
+  fn keeps_message_and_drops_rejected_value() {
+    let post = Post {
+      title: "ab".to_string(),
+      author: Author { name: "a".to_string() },
+      tags: vec![],
+    };
+
+    let errors = FieldError::collect(&post.validate().unwrap_err());
+
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].message.as_deref(), Some("too short"));
+    assert_eq!(errors[0].params.get("min"), Some(&serde_json::json!(3)));
+    assert!(!errors[0].params.contains_key("value"));
+  }
+}
@@ -0,0 +1,165 @@
+use std::sync::Arc;
+use actix_boot::di::{ComponentInfo, DIContext, DIError};
+use actix_boot::service::derive::Service;
+use sea_orm::DatabaseConnection;
+
+trait Greeter: Send + Sync {}
+
+#[derive(Service)]
+#[binds(dyn Greeter)]
+struct EnglishGreeter {}
+
+impl Greeter for EnglishGreeter {}
+
+#[derive(Service)]
+#[binds(dyn Greeter)]
+struct GermanGreeter {}
+
+impl Greeter for GermanGreeter {}
+
+#[derive(Service)]
+#[allow(dead_code)]
+struct Welcome {
+  greeter: Arc<dyn Greeter>,
+}
+
+trait Formatter: Send + Sync {
+  fn format(&self) -> &'static str;
+}
+
+#[derive(Service)]
+#[binds(dyn Formatter)]
+struct TextFormatter {}
+
+impl Formatter for TextFormatter {
+  fn format(&self) -> &'static str {
+    "text"
+  }
+}
+
+#[derive(Service)]
+#[binds(dyn Formatter)]
+#[primary]
+struct JsonFormatter {}
+
+impl Formatter for JsonFormatter {
+  fn format(&self) -> &'static str {
+    "json"
+  }
+}
+
+#[derive(Service)]
+struct Report {
+  formatter: Arc<dyn Formatter>,
+}
+
+trait Handler: Send + Sync {
+  fn name(&self) -> &'static str;
+}
+
+#[derive(Service)]
+#[binds(dyn Handler)]
+#[order(2)]
+struct AuditHandler {}
+
+impl Handler for AuditHandler {
+  fn name(&self) -> &'static str {
+    "audit"
+  }
+}
+
+#[derive(Service)]
+#[binds(dyn Handler)]
+#[order(-1)]
+struct AuthHandler {}
+
+impl Handler for AuthHandler {
+  fn name(&self) -> &'static str {
+    "auth"
+  }
+}
+
+#[derive(Service)]
+#[binds(dyn Handler)]
+struct MetricsHandler {}
+
+impl Handler for MetricsHandler {
+  fn name(&self) -> &'static str {
+    "metrics"
+  }
+}
+
+#[derive(Service)]
+struct Pipeline {
+  handlers: Vec<Arc<dyn Handler>>,
+}
+
+fn context() -> DIContext {
+  let context = DIContext::new(DatabaseConnection::Disconnected);
+  context.register_discovered();
+  context
+}
+
+#[test]
+fn rejects_ambiguous_binding() {
+  let err = context().try_get_binding::<dyn Greeter>().err().expect("ambiguous binding must fail");
+
+  let DIError::AmbiguousBinding { mut candidates, .. } = err.clone() else {
+    panic!("unexpected error {}", err);
+  };
+
+  candidates.sort();
+  assert_eq!(candidates, ["EnglishGreeter", "GermanGreeter"]);
+  assert!(err.to_string().starts_with("Multiple candidates for dyn bindings::Greeter: "));
+}
+
+#[test]
+fn validation_reports_ambiguous_binding() {
+  let Err(DIError::Validation(errors)) = context().validate(&[ComponentInfo::service::<Welcome>()]) else {
+    panic!("validation must fail");
+  };
+
+  assert_eq!(errors.len(), 1);
+  assert!(matches!(&errors[0], DIError::Unresolvable { field: "greeter", cause, .. }
+    if matches!(**cause, DIError::AmbiguousBinding { .. })));
+}
+
+#[test]
+fn resolves_primary_binding() {
+  let context = context();
+
+  context.validate(&[ComponentInfo::service::<Report>()]).unwrap();
+
+  assert_eq!(context.get_binding::<dyn Formatter>().format(), "json");
+  assert_eq!(context.get_service::<Report>().formatter.format(), "json");
+}
+
+#[test]
+fn orders_collection_by_order_attribute() {
+  let context = context();
+
+  context.validate(&[ComponentInfo::service::<Pipeline>()]).unwrap();
+
+  let names = context.get_service::<Pipeline>().handlers.iter().map(|handler| handler.name()).collect::<Vec<_>>();
+
+  assert_eq!(names, ["auth", "metrics", "audit"]);
+}
+
+#[test]
+fn exports_edge_per_binding_candidate() {
+  let graph = context().dependency_graph(&[ComponentInfo::service::<Welcome>(), ComponentInfo::service::<Report>()]);
+
+  let mut edges = graph.edges.iter()
+    .filter(|edge| edge.field == "binds")
+    .map(|edge| (edge.from.as_str(), edge.to.as_str(), edge.ambiguous))
+    .collect::<Vec<_>>();
+  edges.sort();
+
+  assert_eq!(edges, [
+    ("dyn bindings::Formatter", "bindings::JsonFormatter", false),
+    ("dyn bindings::Formatter", "bindings::TextFormatter", false),
+    ("dyn bindings::Greeter", "bindings::EnglishGreeter", true),
+    ("dyn bindings::Greeter", "bindings::GermanGreeter", true),
+  ]);
+  assert!(graph.to_dot().contains(r#""dyn bindings::Greeter" -> "bindings::GermanGreeter" [label="binds", style=solid, color=red];"#));
+}
@@ -0,0 +1,44 @@
+use actix_boot::repository::macros::repository;
+use actix_boot::repository::{CursorPage, QueryError};
+use sea_orm::entity::prelude::*;
+
+pub mod post {
+  use sea_orm::entity::prelude::*;
+
+  #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+  #[sea_orm(table_name = "post")]
+  pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub title: String,
+  }
+
+  #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+  pub enum Relation {}
+
+  impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[repository(post)]
+pub trait PostRepositoryBase {
+  async fn find_all_by_title_order_by_id_desc_cursor(&self, title: &str, cursor: Option<String>, size: u64) -> Result<CursorPage<post::Model>, QueryError>;
+}
+
+fn repository() -> PostRepository {
+  PostRepository::from(DatabaseConnection::Disconnected)
+}
+
+#[tokio::test]
+async fn rejects_empty_pages() {
+  let err = repository().find_all_by_title_order_by_id_desc_cursor("rust", None, 0).await.unwrap_err();
+
+  assert!(matches!(err, QueryError::InvalidPageSize(0)));
+}
+
+#[tokio::test]
+async fn rejects_invalid_cursor() {
+  let err = repository().find_all_by_title_order_by_id_desc_cursor("rust", Some("not a cursor".to_string()), 10).await.unwrap_err();
+
+  assert!(matches!(err, QueryError::InvalidCursor(cursor) if cursor == "not a cursor"));
+}
+
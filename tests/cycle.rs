@@ -0,0 +1,100 @@
+use std::sync::Arc;
+use actix_boot::di::{ComponentInfo, DIContext, DIError, Lazy, Provider};
+use actix_boot::service::derive::Service;
+use sea_orm::DatabaseConnection;
+
+#[derive(Service)]
+#[allow(dead_code)]
+struct OrderService {
+  billing_service: Arc<BillingService>,
+}
+
+#[derive(Service)]
+#[allow(dead_code)]
+struct BillingService {
+  order_service: Arc<OrderService>,
+}
+
+#[derive(Service)]
+struct LazyOrderService {
+  billing: Lazy<LazyBillingService>,
+}
+
+#[derive(Service)]
+struct LazyBillingService {
+  order_service: Arc<LazyOrderService>,
+}
+
+#[derive(Service)]
+struct ProviderOrderService {
+  billing: Provider<ProviderBillingService>,
+}
+
+#[derive(Service)]
+struct ProviderBillingService {
+  order_service: Arc<ProviderOrderService>,
+}
+
+fn context() -> Arc<DIContext> {
+  DIContext::new(DatabaseConnection::Disconnected).into_shared()
+}
+
+#[test]
+fn reports_cycle_path() {
+  let err = context().try_get_service::<OrderService>().err().expect("cycle must fail");
+
+  assert!(matches!(err, DIError::Cycle { .. }));
+  assert_eq!(err.to_string(), "cycle: OrderService -> BillingService -> OrderService");
+}
+
+#[test]
+fn reports_cycle_from_either_side() {
+  let err = context().try_get_service::<BillingService>().err().expect("cycle must fail");
+
+  assert_eq!(err.to_string(), "cycle: BillingService -> OrderService -> BillingService");
+}
+
+#[test]
+#[should_panic(expected = "cycle: OrderService -> BillingService -> OrderService")]
+fn get_service_panics_with_cycle() {
+  context().get_service::<OrderService>();
+}
+
+#[test]
+fn validation_reports_cycle() {
+  let err = context().validate(&[ComponentInfo::service::<OrderService>()]).unwrap_err();
+
+  assert!(err.to_string().contains("cycle: OrderService -> BillingService -> OrderService"));
+}
+
+#[test]
+fn lazy_breaks_cycle() {
+  let context = context();
+
+  context.validate(&[ComponentInfo::service::<LazyOrderService>()]).unwrap();
+
+  let order = context.try_get_service::<LazyOrderService>().unwrap();
+
+  assert!(Arc::ptr_eq(&order.billing.order_service, &order));
+}
+
+#[test]
+fn provider_breaks_cycle() {
+  let context = context();
+
+  context.validate(&[ComponentInfo::service::<ProviderOrderService>()]).unwrap();
+
+  let order = context.try_get_service::<ProviderOrderService>().unwrap();
+  let billing = order.billing.try_get().unwrap();
+
+  assert!(Arc::ptr_eq(&billing.order_service, &order));
+  assert!(Arc::ptr_eq(&order.billing.get(), &billing));
+}
+
+#[test]
+fn lazy_requires_shared_context() {
+  let context = DIContext::new(DatabaseConnection::Disconnected);
+  let err = context.try_get_service::<LazyOrderService>().err().expect("unshared context must fail");
+
+  assert!(matches!(err, DIError::NotShared { .. }));
+}
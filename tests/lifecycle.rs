@@ -0,0 +1,180 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use actix_boot::di::{ComponentInfo, DIContext, DIError};
+use actix_boot::service::derive::Service;
+use actix_boot::service::macros::lifecycle;
+use sea_orm::DatabaseConnection;
+
+#[derive(Service)]
+struct Store {
+  #[default]
+  connected: AtomicBool,
+}
+
+#[lifecycle]
+impl Store {
+  #[init]
+  async fn connect(&self) -> Result<(), std::io::Error> {
+    self.connected.store(true, Ordering::SeqCst);
+    Ok(())
+  }
+}
+
+#[derive(Service)]
+struct BrokenStore {}
+
+#[lifecycle]
+impl BrokenStore {
+  #[init]
+  async fn connect(&self) -> Result<(), std::io::Error> {
+    Err(std::io::Error::other("connection refused"))
+  }
+}
+
+#[derive(Service)]
+struct FileStore {
+  #[config("store.path")]
+  path: String,
+}
+
+#[lifecycle]
+impl FileStore {
+  #[init]
+  async fn open(&self) -> Result<(), std::io::Error> {
+    std::fs::metadata(&self.path).map(|_| ())
+  }
+}
+
+#[derive(Service)]
+struct Reporter {}
+
+#[derive(Service)]
+struct Journal {
+  #[default]
+  events: Mutex<Vec<&'static str>>,
+}
+
+impl Journal {
+  fn record(&self, event: &'static str) {
+    self.events.lock().unwrap().push(event);
+  }
+}
+
+#[derive(Service)]
+struct Cache {
+  journal_service: Arc<Journal>,
+  #[default]
+  warm: AtomicBool,
+}
+
+#[lifecycle]
+impl Cache {
+  #[post_construct]
+  fn warm_up(&self) {
+    self.warm.store(true, Ordering::SeqCst);
+    self.journal_service.record("cache constructed");
+  }
+
+  #[pre_destroy]
+  async fn flush(&self) -> Result<(), std::io::Error> {
+    self.journal_service.record("cache destroyed");
+    Ok(())
+  }
+}
+
+#[derive(Service)]
+struct Index {
+  journal_service: Arc<Journal>,
+  cache_service: Arc<Cache>,
+}
+
+#[lifecycle]
+impl Index {
+  #[post_construct]
+  fn load(&self) {
+    if self.cache_service.warm.load(Ordering::SeqCst) {
+      self.journal_service.record("index constructed");
+    }
+  }
+
+  #[pre_destroy]
+  async fn close(&self) -> Result<(), std::io::Error> {
+    self.journal_service.record("index destroyed");
+    Ok(())
+  }
+}
+
+fn context() -> DIContext {
+  DIContext::new(DatabaseConnection::Disconnected)
+}
+
+#[tokio::test]
+async fn runs_init_during_startup() {
+  let context = context();
+
+  context.initialize(&[ComponentInfo::service::<Store>()]).await.unwrap();
+
+  assert!(context.get_service::<Store>().connected.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn reports_failed_init() {
+  let err = context().initialize(&[ComponentInfo::service::<BrokenStore>()]).await.unwrap_err();
+
+  assert_eq!(err, DIError::Init {
+    service: "BrokenStore".to_string(),
+    message: "connection refused".to_string(),
+  });
+}
+
+#[tokio::test]
+async fn reports_failed_construction_without_panicking() {
+  let err = context().initialize(&[ComponentInfo::service::<FileStore>()]).await.unwrap_err();
+
+  assert!(matches!(&err, DIError::Init { service, message } if service == "FileStore" && message.contains("store.path")));
+}
+
+#[test]
+fn rejects_resolving_before_init() {
+  let err = context().try_get_service::<Store>().err().expect("uninitialized service must fail");
+
+  assert_eq!(err, DIError::NotInitialized { service: "Store".to_string() });
+}
+
+#[test]
+fn validation_reports_unscheduled_init_services() {
+  let context = context();
+  context.register_discovered();
+
+  let Err(DIError::Validation(errors)) = context.validate(&[ComponentInfo::service::<Reporter>(), ComponentInfo::service::<Store>()]) else {
+    panic!("validation must fail");
+  };
+
+  assert_eq!(errors, [
+    DIError::NotInitialized { service: "BrokenStore".to_string() },
+    DIError::NotInitialized { service: "FileStore".to_string() },
+  ]);
+}
+
+#[test]
+fn runs_post_construct_after_injection() {
+  let context = context();
+
+  assert!(context.get_service::<Cache>().warm.load(Ordering::SeqCst));
+}
+
+#[tokio::test]
+async fn runs_pre_destroy_in_reverse_dependency_order() {
+  let context = context();
+  let journal = context.get_service::<Journal>();
+  context.get_service::<Index>();
+
+  context.destroy().await.unwrap();
+
+  assert_eq!(*journal.events.lock().unwrap(), [
+    "cache constructed",
+    "index constructed",
+    "index destroyed",
+    "cache destroyed",
+  ]);
+}
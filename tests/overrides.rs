@@ -0,0 +1,116 @@
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+use actix_boot::di::{ComponentInfo, DIContext};
+use actix_boot::repository::macros::repository;
+use actix_boot::service::derive::Service;
+use sea_orm::entity::prelude::*;
+
+pub mod post {
+  use sea_orm::entity::prelude::*;
+
+  #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+  #[sea_orm(table_name = "post")]
+  pub struct Model {
+    #[sea_orm(primary_key)]
+    pub id: i32,
+    pub title: String,
+  }
+
+  #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+  pub enum Relation {}
+
+  impl ActiveModelBehavior for ActiveModel {}
+}
+
+type Posts<'a> = Pin<Box<dyn Future<Output = Result<Vec<post::Model>, DbErr>> + Send + 'a>>;
+
+trait PostStore: Send + Sync {
+  fn find_by_title<'a>(&'a self, title: &'a str) -> Posts<'a>;
+}
+
+#[repository(post, binds(dyn PostStore))]
+pub trait PostRepositoryBase {
+  async fn find_all_by_title(&self, title: &str) -> Result<Vec<post::Model>, DbErr>;
+}
+
+impl PostStore for PostRepository {
+  fn find_by_title<'a>(&'a self, title: &'a str) -> Posts<'a> {
+    Box::pin(self.find_all_by_title(title))
+  }
+}
+
+struct FakePostStore;
+
+impl PostStore for FakePostStore {
+  fn find_by_title<'a>(&'a self, title: &'a str) -> Posts<'a> {
+    Box::pin(async move { Ok(vec![post::Model { id: 7, title: title.to_string() }]) })
+  }
+}
+
+#[derive(Service)]
+struct ClockService {
+  #[config("clock.zone")]
+  zone: String,
+}
+
+trait Mailer: Send + Sync {
+  fn send(&self, title: &str) -> String;
+}
+
+struct FakeMailer;
+
+impl Mailer for FakeMailer {
+  fn send(&self, title: &str) -> String {
+    format!("sent {}", title)
+  }
+}
+
+#[derive(Service)]
+struct PostService {
+  post_store: Arc<dyn PostStore>,
+  clock_service: Arc<ClockService>,
+  mailer: Arc<dyn Mailer>,
+}
+
+impl PostService {
+  async fn announce(&self, title: &str) -> Result<Vec<String>, DbErr> {
+    let posts = self.post_store.find_by_title(title).await?;
+
+    Ok(posts.iter()
+      .map(|post| format!("{} {} ({})", self.mailer.send(&post.title), post.id, self.clock_service.zone))
+      .collect())
+  }
+}
+
+#[tokio::test]
+async fn resolves_service_with_overridden_dependencies() {
+  let context = DIContext::new(DatabaseConnection::Disconnected);
+  context.override_binding::<dyn PostStore>(Arc::new(FakePostStore));
+  context.override_service(ClockService { zone: "UTC".to_string() });
+  context.override_binding::<dyn Mailer>(Arc::new(FakeMailer));
+
+  context.validate(&[ComponentInfo::service::<PostService>()]).unwrap();
+
+  let service = context.get_service::<PostService>();
+
+  assert_eq!(service.announce("Hello").await.unwrap(), ["sent Hello 7 (UTC)"]);
+}
+
+#[test]
+fn binds_repository_to_trait_object() {
+  let context = DIContext::new(DatabaseConnection::Disconnected);
+  context.register_discovered();
+
+  let repository = context.get_repository::<PostRepository>();
+  let store = context.get_binding::<dyn PostStore>();
+
+  assert!(std::ptr::addr_eq(Arc::as_ptr(&store), Arc::as_ptr(&repository)));
+}
+
+#[test]
+fn validation_requires_overridden_dependencies() {
+  let context = DIContext::new(DatabaseConnection::Disconnected);
+
+  assert!(context.validate(&[ComponentInfo::service::<PostService>()]).is_err());
+}
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+use actix_boot::di::{ComponentInfo, DIContext, DIError};
+use actix_boot::service::derive::Service;
+use sea_orm::DatabaseConnection;
+
+trait Notifier: Send + Sync {}
+
+#[derive(Service)]
+#[allow(dead_code)]
+struct Signup {
+  notifier: Arc<dyn Notifier>,
+}
+
+#[derive(Service)]
+#[allow(dead_code)]
+struct OptionalSignup {
+  notifier: Option<Arc<dyn Notifier>>,
+}
+
+#[derive(Service)]
+#[scope(request)]
+struct CurrentUser {}
+
+#[derive(Service)]
+#[allow(dead_code)]
+struct AuditLog {
+  #[inject]
+  user: Arc<CurrentUser>,
+}
+
+#[derive(Service)]
+#[scope(prototype)]
+#[allow(dead_code)]
+struct AuditEntry {
+  #[inject]
+  user: Arc<CurrentUser>,
+}
+
+#[derive(Service)]
+#[profile("metrics")]
+struct MetricsService {}
+
+#[derive(Service)]
+#[allow(dead_code)]
+struct Dashboard {
+  metrics_service: Arc<MetricsService>,
+}
+
+#[derive(Service)]
+#[allow(dead_code)]
+struct OptionalDashboard {
+  metrics: Option<Arc<MetricsService>>,
+}
+
+fn context() -> DIContext {
+  DIContext::new(DatabaseConnection::Disconnected)
+}
+
+fn errors(result: Result<(), DIError>) -> Vec<DIError> {
+  match result {
+    Err(DIError::Validation(errors)) => errors,
+    Err(err) => panic!("unexpected error {}", err),
+    Ok(()) => Vec::new(),
+  }
+}
+
+#[test]
+fn reports_missing_binding() {
+  let errors = errors(context().validate(&[ComponentInfo::service::<Signup>()]));
+
+  assert_eq!(errors.len(), 1);
+  assert!(matches!(&errors[0], DIError::Unresolvable { component, field: "notifier", cause }
+    if component == "Signup" && matches!(**cause, DIError::MissingBinding { .. })));
+}
+
+#[test]
+fn accepts_missing_optional_binding() {
+  let context = context();
+
+  context.validate(&[ComponentInfo::service::<OptionalSignup>()]).unwrap();
+  assert!(context.get_service::<OptionalSignup>().notifier.is_none());
+}
+
+#[test]
+fn reports_request_scoped_dependency_of_singleton() {
+  let errors = errors(context().validate(&[ComponentInfo::service::<AuditLog>()]));
+
+  assert_eq!(errors.len(), 1);
+  assert_eq!(errors[0].to_string(), "AuditLog.user: singleton depends on request scoped CurrentUser");
+}
+
+#[test]
+fn accepts_request_scoped_dependency_of_prototype() {
+  context().validate(&[ComponentInfo::service::<AuditEntry>()]).unwrap();
+}
+
+#[test]
+fn reports_disabled_dependency() {
+  let context = context();
+  context.register_discovered();
+
+  let errors = errors(context.validate(&[ComponentInfo::service::<Dashboard>()]));
+
+  assert_eq!(errors.len(), 1);
+  assert!(matches!(&errors[0], DIError::Unresolvable { field: "metrics_service", cause, .. }
+    if matches!(**cause, DIError::DisabledComponent { .. })));
+  assert!(matches!(context.try_get_service::<MetricsService>(), Err(DIError::DisabledComponent { .. })));
+}
+
+#[test]
+fn accepts_disabled_optional_dependency() {
+  let context = context();
+  context.register_discovered();
+
+  context.validate(&[ComponentInfo::service::<OptionalDashboard>()]).unwrap();
+  assert!(context.get_service::<OptionalDashboard>().metrics.is_none());
+}
+
+#[test]
+fn reports_all_errors_at_once() {
+  let context = context();
+  context.register_discovered();
+
+  let errors = errors(context.validate(&[
+    ComponentInfo::service::<Signup>(),
+    ComponentInfo::service::<AuditLog>(),
+    ComponentInfo::service::<Dashboard>(),
+  ]));
+
+  assert_eq!(errors.len(), 3);
+}